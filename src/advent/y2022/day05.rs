@@ -0,0 +1,302 @@
+use super::*;
+use itertools::Itertools;
+use regex::Regex;
+use std::ops::Range;
+
+const DESCRIPTION: &str = r#"--- Day 5: Supply Stacks ---
+The expedition can depart as soon as the final supplies have been unloaded
+from the ships. Supplies are stored in stacks of marked crates, but because
+the needed supplies are buried under many other crates, the crates need to
+be rearranged.
+
+The ship has a giant cargo crane capable of moving crates between stacks.
+To ensure none of the crates get crushed or fall over, the crane operator
+will rearrange them in a series of carefully-planned steps. After the
+crates are rearranged, the desired crates will be at the top of each stack.
+
+The Elves don't want to interrupt the crane operator during this delicate
+procedure, but they forgot to ask her which crate will end up where, and
+they want to be ready to unload them as soon as possible so they can embark.
+
+They do, however, have a drawing of the starting stacks of crates and the
+rearrangement procedure (your puzzle input). For example:
+
+    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+
+In this example, there are three stacks of crates. Stack 1 contains two
+crates: crate Z is on the bottom, and crate N is on top. Stack 2 contains
+three crates; from bottom to top, they are crates M, C, and D. Finally,
+stack 3 contains a single crate, P.
+
+Then, the rearrangement procedure is given. In each step of the procedure,
+a quantity of crates is moved from one stack to a different stack. In the
+first step of the above rearrangement procedure, one crate is moved from
+stack 2 to stack 1, resulting in this configuration:
+
+[D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+In the second step, three crates are moved from stack 1 to stack 3. Crates
+are moved one at a time, so the first crate to be moved (D) ends up below
+the second and third crates:
+        [Z]
+        [N]
+    [C] [D]
+    [M] [P]
+ 1   2   3
+
+Then, both crates are moved from stack 2 to stack 1. Again, because crates
+are moved one at a time, crate C ends up below crate M:
+        [Z]
+        [N]
+[M]     [D]
+[C]     [P]
+ 1   2   3
+
+Finally, one crate is moved from stack 1 to stack 2:
+        [Z]
+        [N]
+        [D]
+[C] [M] [P]
+ 1   2   3
+
+The Elves just need to know which crate will end up on top of each stack;
+in this example, the top crates are C in stack 1, M in stack 2, and Z in
+stack 3, so you should combine these together and give the Elves the
+message CMZ.
+
+After the rearrangement procedure completes, what crate ends up on top of
+each stack?
+
+--- Part Two ---
+As you watch the crane operator expertly rearrange the crates, you notice
+the process isn't following your prediction.
+
+Some mud was covering the writing on the side of the crane, and you quickly
+wipe it away. The crane isn't a CrateMover 9000 - it's a CrateMover 9001.
+
+The CrateMover 9001 is notable for many new and exciting features: air
+conditioning, leather seats, an extra cup holder, and the ability to pick
+up and move multiple crates at once.
+
+Again considering the example above, the crates begin in the same
+configuration:
+    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+Moving a single crate from stack 2 to stack 1 behaves the same as before:
+[D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+However, the action of moving three crates from stack 1 to stack 3 means
+that those three moved crates stay in the same order, resulting in this new
+configuration:
+        [D]
+        [N]
+    [C] [Z]
+    [M] [P]
+ 1   2   3
+
+Next, as both crates are moved from stack 2 to stack 1, they retain their
+order as well:
+        [D]
+        [N]
+[C]     [Z]
+[M]     [P]
+ 1   2   3
+
+Finally, a single crate is still moved from stack 1 to stack 2, but now
+it's crate C that gets moved:
+        [D]
+        [N]
+        [Z]
+[M] [C] [P]
+ 1   2   3
+
+In this example, the CrateMover 9001 has put the crates in a totally
+different order: MCD.
+
+Before the rearrangement process finishes, update your simulation so that
+the Elves know where they should stand to be ready to unload the final
+supplies. After the rearrangement procedure completes, what crate ends up
+on top of each stack?"#;
+
+#[derive(Default)]
+pub struct Day05 {}
+
+register!(Day05 {
+    day: 5,
+    title: "Supply Stacks",
+    example: ("CMZ", "MCD"),
+});
+
+impl Puzzle for Day05 {
+    fn part_one(&self, data: &str) -> Result<Answer> {
+        let (mut cargo, ops) = parse(data)?;
+        for op in ops {
+            for _ in 0..op.count {
+                let c = cargo[op.from].pop().context("Move from an empty stack")?;
+                cargo[op.to].push(c);
+            }
+        }
+        top_crates(&cargo).map(Answer::from)
+    }
+
+    fn part_two(&self, data: &str) -> Result<Answer> {
+        let (mut cargo, ops) = parse(data)?;
+        for op in ops {
+            let count = cargo[op.from]
+                .len()
+                .checked_sub(op.count)
+                .context("Move from an empty stack")?;
+
+            let group = cargo[op.from][count..].to_vec();
+            cargo[op.from].truncate(count);
+            cargo[op.to].extend(group);
+        }
+        top_crates(&cargo).map(Answer::from)
+    }
+
+    fn parse(&self, data: &str) -> Option<Result<()>> {
+        Some(parse(data).map(drop))
+    }
+
+    fn validate(&self, data: &str) -> Vec<Diagnostic> {
+        let moves = containers(data).and_then(|cargo| move_line(cargo.len()));
+        match moves {
+            Ok(move_line) => validate_lines(data, move_line),
+            Err(err) => vec![Diagnostic::new(data, &err)],
+        }
+    }
+}
+
+/// Crates of each stack from the bottom up, and the moves of the crane
+pub fn parse(data: &str) -> Result<(Vec<Vec<char>>, Vec<Op>)> {
+    let cargo = containers(data)?;
+    let ops = operations(data, cargo.len())?;
+    Ok((cargo, ops))
+}
+
+/// Reads the drawing above the moves, placing every crate on the stack whose label is
+/// written below it, so neither the number of stacks nor their heights are fixed
+fn containers(data: &str) -> Result<Vec<Vec<char>>> {
+    let drawing = data
+        .lines()
+        .take_while(|l| !l.trim().is_empty())
+        .collect_vec();
+    let (labels, rows) = drawing.split_last().context("Missing the stacks drawing")?;
+    let labels = stack_labels(labels)?;
+
+    let mut cargo = vec![vec![]; labels.len()];
+    for (height, row) in rows.iter().rev().enumerate() {
+        for (col, _) in row.match_indices('[') {
+            let Some((c, ']')) = row[col + 1..].chars().next_tuple() else {
+                bail!("Unclosed crate in {:?}", row);
+            };
+            if !c.is_ascii_uppercase() {
+                bail!("Invalid crate {:?}", c);
+            }
+
+            let n = labels
+                .iter()
+                .position(|span| span.start <= col + 2 && col < span.end);
+            let stack = n.and_then(|n| cargo.get_mut(n));
+            match stack {
+                Some(stack) if stack.len() == height => stack.push(c),
+                Some(_) => bail!("Crate {:?} floats above an empty spot", c),
+                None => bail!("Crate {:?} is not above any labeled stack", c),
+            }
+        }
+    }
+    Ok(cargo)
+}
+
+/// Column spans of the stack labels, which must count up from 1
+fn stack_labels(line: &str) -> Result<Vec<Range<usize>>> {
+    let mut spans = vec![];
+    let mut chars = line.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        while let Some((i, c)) = chars.next_if(|(_, c)| !c.is_whitespace()) {
+            end = i + c.len_utf8();
+        }
+
+        let label = &line[start..end];
+        if parse!(label as usize)? != spans.len() + 1 {
+            bail!(
+                "Expected stack {} but found label {:?}",
+                spans.len() + 1,
+                label
+            );
+        }
+        spans.push(start..end);
+    }
+    if spans.is_empty() {
+        bail!("Missing the stack labels below the drawing");
+    }
+    Ok(spans)
+}
+
+fn top_crates(cargo: &[Vec<char>]) -> Result<String> {
+    cargo
+        .iter()
+        .map(|c| c.last().context("Empty stack at the end"))
+        .collect()
+}
+
+fn operations(data: &str, stacks: usize) -> Result<Vec<Op>> {
+    let ops = parse_lines(data, move_line(stacks)?)?;
+    Ok(ops.into_iter().flatten().collect())
+}
+
+/// Reads the lines of the input in turn, skipping the drawing and blank lines, but
+/// rejecting any other line that is not a move
+fn move_line(stacks: usize) -> Result<impl FnMut(&str) -> Result<Option<Op>>> {
+    let re = re!(r"move (\d+) from (\d+) to (\d+)")?;
+    let mut below_drawing = false;
+    Ok(move |line: &str| {
+        let blank = line.trim().is_empty();
+        below_drawing |= blank;
+        match below_drawing && !blank {
+            true => Op::from(line, re, stacks).map(Some),
+            false => Ok(None),
+        }
+    })
+}
+
+/// Move of `count` crates, with the stacks numbered from 0
+pub struct Op {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+impl Op {
+    fn new(count: usize, from: usize, to: usize) -> Self {
+        Self { count, from, to }
+    }
+    fn from(line: &str, re: &Regex, stacks: usize) -> Result<Self> {
+        let (count, from, to) = captures!(line, re)?;
+        let stack = |s: &str| match parse!(s as usize)? {
+            n if (1..=stacks).contains(&n) => Ok(n - 1),
+            n => bail!("Stack {} does not exist", n),
+        };
+        Ok(Self::new(parse!(count)?, stack(from)?, stack(to)?))
+    }
+}
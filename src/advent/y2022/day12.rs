@@ -0,0 +1,172 @@
+use super::*;
+use crate::{grid::Grid, search::bfs};
+use pt::P2;
+
+const DESCRIPTION: &str = r#"--- Day 12: Hill Climbing Algorithm ---
+You try contacting the Elves using your handheld device, but the river
+you're following must be too low to get a decent signal.
+
+You ask the device for a heightmap of the surrounding area (your puzzle
+input). The heightmap shows the local area from above broken into a grid;
+the elevation of each square of the grid is given by a single lowercase
+letter, where a is the lowest elevation, b is the next-lowest, and so on up
+to the highest elevation, z.
+
+Also included on the heightmap are marks for your current position (S) and
+the location that should get the best signal (E). Your current position (S)
+has elevation a, and the location that should get the best signal (E) has
+elevation z.
+
+You'd like to reach E, but to save energy, you should do it in as few steps
+as possible. During each step, you can move exactly one square up, down,
+left, or right. To avoid needing to get out your climbing gear, the
+elevation of the destination square can be at most one higher than the
+elevation of your current square; that is, if your current elevation is m,
+you could step to elevation n, but not to elevation o. (This also means
+that the elevation of the destination square can be much lower than the
+elevation of your current square.)
+
+For example:
+
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+
+Here, you start in the top-left corner; your goal is near the middle. You
+could start by moving down or right, but eventually you'll need to head
+toward the e at the bottom. From there, you can spiral around to the goal:
+
+v..v<<<<
+>v.vv<<^
+.>vv>E^^
+..v>>>^^
+..>>>>>^
+
+In the above diagram, the symbols indicate whether the path exits each
+square moving up (^), down (v), left (<), or right (>). The location
+that should get the best signal is still E, and . marks unvisited squares.
+
+This path reaches the goal in 31 steps, the fewest possible.
+
+What is the fewest steps required to move from your current position to the
+location that should get the best signal?
+
+--- Part Two ---
+As you walk up the hill, you suspect that the Elves will want to turn this
+into a hiking trail. The beginning isn't very scenic, though; perhaps you
+can find a better starting point.
+
+To maximize exercise while hiking, the trail should start as low as
+possible: elevation a. The goal is still the square marked E. However, the
+trail should still be direct, taking the fewest steps to reach its goal.
+So, you'll need to find the shortest path from any square at elevation a to
+the square marked E.
+
+Again consider the example from above:
+
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+
+Now, there are six choices for starting position (five marked a, plus the
+square marked S that counts as being at elevation a). If you start at the
+bottom-left square, you can reach the goal most quickly:
+...v<<<<
+...vv<<^
+...v>E^^
+.>v>>>^^
+>^>>>>>^
+
+This path reaches the goal in only 29 steps, the fewest possible.
+
+What is the fewest steps required to move starting from any square with
+elevation a to the location that should get the best signal?"#;
+
+#[derive(Default)]
+pub struct Day12 {}
+
+register!(Day12 {
+    day: 12,
+    title: "Hill Climbing Algorithm",
+    example: ("31", "29"),
+});
+
+impl Puzzle for Day12 {
+    fn part_one(&self, data: &str) -> Result<Answer> {
+        let HeightMap { graph, src, dst } = parse(data)?;
+        let search = bfs([src], |&p| climbable_neighbours(&graph, p), |&p| p == dst);
+        Ok(search
+            .goal_cost()
+            .context("No path to the best signal")?
+            .into())
+    }
+
+    fn part_two(&self, data: &str) -> Result<Answer> {
+        let HeightMap { graph, dst, .. } = parse(data)?;
+        let lowest = graph.iter().filter(|&(_, &c)| c == b'a').map(|(p, _)| p);
+        let search = bfs(lowest, |&p| climbable_neighbours(&graph, p), |&p| p == dst);
+        Ok(search
+            .goal_cost()
+            .context("No path from the lowest elevation")?
+            .into())
+    }
+
+    fn parse(&self, data: &str) -> Option<Result<()>> {
+        Some(parse(data).map(drop))
+    }
+}
+
+/// Position as (column, row)
+pub type Point = P2<i32>;
+/// Elevations as letters
+pub type Graph = Grid<u8>;
+
+/// Elevations of the area with the current position and the location with the best signal,
+/// which are at elevations `a` and `z`
+pub struct HeightMap {
+    pub graph: Graph,
+    pub src: Point,
+    pub dst: Point,
+}
+
+pub fn parse(data: &str) -> Result<HeightMap> {
+    HeightMap::from(data)
+}
+impl HeightMap {
+    fn from(data: &str) -> Result<Self> {
+        let expected = "an elevation from 'a' to 'z', 'S' or 'E'";
+        let mut graph = Grid::parse(data, expected, |c| match c {
+            'a'..='z' | 'S' | 'E' => Some(c as u8),
+            _ => None,
+        })?;
+        let src = Self::mark(&mut graph, b'S', b'a', "starting position 'S'")?;
+        let dst = Self::mark(&mut graph, b'E', b'z', "best signal position 'E'")?;
+        Ok(Self { graph, src, dst })
+    }
+
+    /// Finds the only `marker` on the map and replaces it with its elevation
+    fn mark(graph: &mut Graph, marker: u8, elevation: u8, what: &str) -> Result<Point> {
+        let found = graph.iter().filter(|&(_, &c)| c == marker).map(|(p, _)| p);
+        match found.collect::<Vec<_>>()[..] {
+            [] => bail!("Missing {}", what),
+            [p] => {
+                graph[p] = elevation;
+                Ok(p)
+            }
+            [_, other, ..] => {
+                let expected = Expected::at(other.x as usize + 1, format!("only one {}", what));
+                Err(expected.on_line(other.y as usize + 1))
+            }
+        }
+    }
+}
+
+/// Positions one step away that are at most one higher than `p`
+fn climbable_neighbours(graph: &Graph, p: Point) -> Vec<Point> {
+    let neighbours = graph.neighbours4(p);
+    neighbours.filter(|&n| graph[n] <= graph[p] + 1).collect()
+}
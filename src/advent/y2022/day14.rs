@@ -0,0 +1,355 @@
+use super::*;
+use crate::grid::Grid;
+use itertools::{izip, Itertools};
+use log::trace;
+use pt::{pt, P2};
+
+const DESCRIPTION: &str = r#"--- Day 14: Regolith Reservoir ---
+The distress signal leads you to a giant waterfall! Actually, hang on - the
+signal seems like it's coming from the waterfall itself, and that doesn't
+make any sense. However, you do notice a little path that leads behind the
+waterfall.
+
+Correction: the distress signal leads you behind a giant waterfall! There
+seems to be a large cave system here, and the signal definitely leads
+further inside.
+
+As you begin to make your way deeper underground, you feel the ground
+rumble for a moment. Sand begins pouring into the cave! If you don't
+quickly figure out where the sand is going, you could quickly become
+trapped!
+
+Fortunately, your familiarity with analyzing the path of falling material
+will come in handy here. You scan a two-dimensional vertical slice of the
+cave above you (your puzzle input) and discover that it is mostly air with
+structures made of rock.
+
+Your scan traces the path of each solid rock structure and reports the x,y
+coordinates that form the shape of the path, where x represents distance to
+the right and y represents distance down. Each path appears as a single
+line of text in your scan. After the first point of each path, each point
+indicates the end of a straight horizontal or vertical line to be drawn
+from the previous point. For example:
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+
+This scan means that there are two paths of rock; the first path consists
+of two straight lines, and the second path consists of three straight
+lines. (Specifically, the first path consists of a line of rock from 498,4
+through 498,6 and another line of rock from 498,6 through 496,6.)
+
+The sand is pouring into the cave from point 500,0.
+
+Drawing rock as #, air as ., and the source of the sand as +, this becomes:
+
+  4     5  5
+  9     0  0
+  4     0  3
+0 ......+...
+1 ..........
+2 ..........
+3 ..........
+4 ....#...##
+5 ....#...#.
+6 ..###...#.
+7 ........#.
+8 ........#.
+9 #########.
+
+Sand is produced one unit at a time, and the next unit of sand is not
+produced until the previous unit of sand comes to rest. A unit of sand is
+large enough to fill one tile of air in your scan.
+
+A unit of sand always falls down one step if possible. If the tile
+immediately below is blocked (by rock or sand), the unit of sand attempts
+to instead move diagonally one step down and to the left. If that tile is
+blocked, the unit of sand attempts to instead move diagonally one step down
+and to the right. Sand keeps moving as long as it is able to do so, at each
+step trying to move down, then down-left, then down-right. If all three
+possible destinations are blocked, the unit of sand comes to rest and no
+longer moves, at which point the next unit of sand is created back at the
+source.
+
+So, drawing sand that has come to rest as o, the first unit of sand simply
+falls straight down and then stops:
+
+......+...
+..........
+..........
+..........
+....#...##
+....#...#.
+..###...#.
+........#.
+......o.#.
+#########.
+
+The second unit of sand then falls straight down, lands on the first one,
+and then comes to rest to its left:
+
+......+...
+..........
+..........
+..........
+....#...##
+....#...#.
+..###...#.
+........#.
+.....oo.#.
+#########.
+
+After a total of five units of sand have come to rest, they form this
+pattern:
+
+......+...
+..........
+..........
+..........
+....#...##
+....#...#.
+..###...#.
+......o.#.
+....oooo#.
+#########.
+
+After a total of 22 units of sand:
+
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+....#ooo#.
+..###ooo#.
+....oooo#.
+...ooooo#.
+#########.
+
+Finally, only two more units of sand can possibly come to rest:
+
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.
+
+Once all 24 units of sand shown above have come to rest, all further sand
+flows out the bottom, falling into the endless void. Just for fun, the path
+any new sand takes before falling forever is shown here with ~:
+
+.......+...
+.......~...
+......~o...
+.....~ooo..
+....~#ooo##
+...~o#ooo#.
+..~###ooo#.
+..~..oooo#.
+.~o.ooooo#.
+~#########.
+~..........
+~..........
+~..........
+
+Using your scan, simulate the falling sand. How many units of sand come to
+rest before sand starts flowing into the abyss below?
+
+--- Part Two ---
+You realize you misread the scan. There isn't an endless void at the bottom
+of the scan - there's floor, and you're standing on it!
+
+You don't have time to scan the floor, so assume the floor is an infinite
+horizontal line with a y coordinate equal to two plus the highest y
+coordinate of any point in your scan.
+
+In the example above, the highest y coordinate of any point is 9, and so
+the floor is at y=11. (This is as if your scan contained one extra rock
+path like -infinity,11 -> infinity,11.) With the added floor, the
+example above now looks like this:
+
+        ...........+........
+        ....................
+        ....................
+        ....................
+        .........#...##.....
+        .........#...#......
+        .......###...#......
+        .............#......
+        .............#......
+        .....#########......
+        ....................
+<-- etc #################### etc -->
+
+To find somewhere safe to stand, you'll need to simulate falling sand until
+a unit of sand comes to rest at 500,0, blocking the source entirely and
+stopping the flow of sand into the cave. In the example above, the
+situation finally looks like this after 93 units of sand come to rest:
+
+............o............
+...........ooo...........
+..........ooooo..........
+.........ooooooo.........
+........oo#ooo##o........
+.......ooo#ooo#ooo.......
+......oo###ooo#oooo......
+.....oooo.oooo#ooooo.....
+....oooooooooo#oooooo....
+...ooo#########ooooooo...
+..ooooo.......ooooooooo..
+#########################
+
+Using your scan, simulate the falling sand until the source of the sand
+becomes blocked. How many units of sand come to rest?"#;
+
+#[derive(Default)]
+pub struct Day14 {}
+
+register!(Day14 {
+    day: 14,
+    title: "Regolith Reservoir",
+    example: ("24", "93"),
+});
+
+impl Puzzle for Day14 {
+    fn part_one(&self, data: &str) -> Result<Answer> {
+        let rocks = parse(data)?;
+        let mut cave = Cave::from(&rocks)?;
+
+        let units = cave.simulate();
+        Ok(units.into())
+    }
+
+    fn part_two(&self, data: &str) -> Result<Answer> {
+        let rocks = parse(data)?;
+        let mut cave = Cave::inf_from(&rocks)?;
+
+        let units = cave.simulate() + 1;
+        Ok(units.into())
+    }
+
+    fn parse(&self, data: &str) -> Option<Result<()>> {
+        Some(parse(data).map(drop))
+    }
+
+    fn validate(&self, data: &str) -> Vec<Diagnostic> {
+        validate_lines(data, Rock::from)
+    }
+}
+
+/// Paths of the rock formations
+pub fn parse(data: &str) -> Result<Vec<Rock>> {
+    parse_lines(data, Rock::from)
+}
+
+/// Corners of a path of rock as (x, y), joined by straight lines
+#[derive(Debug)]
+pub struct Rock(pub Vec<(u32, u32)>);
+impl Rock {
+    fn from(data: &str) -> Result<Self> {
+        let path = data
+            .split(" -> ")
+            .map(|s| match s.split_once(',') {
+                Some((x, y)) => Ok((parse!(x)?, parse!(y)?)),
+                None => bail!("Expected 'x,y', found {:?}", s),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Rock(path))
+    }
+}
+
+struct Cave {
+    scan: Grid<char>,
+    source: P2<i32>,
+}
+
+impl Cave {
+    fn from(rocks: &[Rock]) -> Result<Self> {
+        if rocks.is_empty() {
+            bail!("No rock formations in the scan");
+        }
+        let (mut xmin, mut xmax) = (500, 500);
+        let mut ymax = u32::MIN;
+        for r in rocks {
+            let (r_xmin, r_xmax) = r.0.iter().map(|(x, _)| x).minmax().into_option().unwrap();
+            let r_ymax = r.0.iter().map(|(_, y)| y).max().unwrap();
+            (xmin, xmax) = (xmin.min(*r_xmin), xmax.max(*r_xmax));
+            ymax = ymax.max(*r_ymax);
+        }
+
+        let (w, h) = ((xmax - xmin) as usize + 1, ymax as usize + 1);
+        let mut scan = Grid::new(w, h, '.');
+        for r in rocks {
+            for (&(x1, y1), &(x2, y2)) in izip!(&r.0, &r.0[1..]) {
+                let (x1, x2) = ((x1 - xmin) as i32, (x2 - xmin) as i32);
+                let (y1, y2) = (y1 as i32, y2 as i32);
+
+                for x in x1.min(x2)..=x1.max(x2) {
+                    for y in y1.min(y2)..=y1.max(y2) {
+                        scan[pt!(x, y)] = '#';
+                    }
+                }
+            }
+        }
+        let source = pt!((500 - xmin) as i32, 0);
+        scan[source] = '+';
+        Ok(Self { scan, source })
+    }
+
+    /// Widens the cave so that the sand piles up to the source before reaching its sides,
+    /// and adds the floor two rows below the lowest rock
+    fn inf_from(rocks: &[Rock]) -> Result<Self> {
+        let cave = Self::from(rocks)?;
+        let (h, w) = (cave.scan.height(), cave.scan.width());
+        let pad = pt!(h as i32 - (cave.source.x - w as i32 / 2), 0);
+
+        let mut scan = Grid::new(w + 2 * h, h + 2, '.');
+        for (p, &c) in cave.scan.iter() {
+            scan[p + pad] = c;
+        }
+        for x in 0..scan.width() as i32 {
+            scan[pt!(x, h as i32 + 1)] = '#';
+        }
+        let source = cave.source + pad;
+        Ok(Self { scan, source })
+    }
+
+    /// Where the sand at `p` moves next, staying put once it settles, or `None` if it falls
+    /// out of the cave or blocks the source
+    fn move_sand(&self, p: P2<i32>) -> Option<P2<i32>> {
+        for dx in [0, -1, 1] {
+            let next = p + pt!(dx, 1);
+            match self.scan.get(next) {
+                None => return None,
+                Some('.') => return Some(next),
+                _ => (),
+            }
+        }
+        (p != self.source).then_some(p)
+    }
+
+    fn drop_sand(&mut self) -> bool {
+        let mut current = self.source;
+        while let Some(next) = self.move_sand(current) {
+            if current == next {
+                self.scan[next] = 'o';
+                return true;
+            }
+            current = next;
+        }
+        false
+    }
+
+    fn simulate(&mut self) -> u32 {
+        let mut sand_units = 0;
+        while self.drop_sand() {
+            sand_units += 1
+        }
+        trace!("Cave with {} units of sand:\n{}", sand_units, self.scan);
+        sand_units
+    }
+}
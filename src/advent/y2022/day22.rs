@@ -0,0 +1,510 @@
+use super::*;
+use crate::grid::SparseGrid;
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    character::complete::{char, i32},
+    multi::many1,
+    IResult, Parser,
+};
+use pt::P2;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+const DESCRIPTION: &str = r#"--- Day 22: Monkey Map ---
+The monkeys take you on a surprisingly easy trail through the jungle.
+They're even going in roughly the right direction according to your
+handheld device's Grove Positioning System.
+
+As you walk, the monkeys explain that the grove is protected by a force
+field. To pass through the force field, you have to enter a password; doing
+so involves tracing a specific path on a strangely-shaped board.
+
+At least, you're pretty sure that's what you have to do; the elephants
+aren't exactly fluent in monkey.
+
+The monkeys give you notes that they took when they last saw the password
+entered (your puzzle input).
+
+For example:
+        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5
+
+The first half of the monkeys' notes is a map of the board. It is comprised
+of a set of open tiles (on which you can move, drawn .) and solid walls
+(tiles which you cannot enter, drawn #).
+
+The second half is a description of the path you must follow. It consists
+of alternating numbers and letters:
+
+- A number indicates the number of tiles to move in the direction you are
+facing. If you run into a wall, you stop moving forward and continue with
+the next instruction.
+- A letter indicates whether to turn 90 degrees clockwise (R) or
+counterclockwise (L). Turning happens in-place; it does not change your
+current tile.
+
+So, a path like 10R5 means "go forward 10 tiles, then turn clockwise 90
+degrees, then go forward 5 tiles".
+
+You begin the path in the leftmost open tile of the top row of tiles.
+Initially, you are facing to the right (from the perspective of how the map
+is drawn).
+
+If a movement instruction would take you off of the map, you wrap around to
+the other side of the board. In other words, if your next tile is off of
+the board, you should instead look in the direction opposite of your
+current facing as far as you can until you find the opposite edge of the
+board, then reappear there.
+
+For example, if you are at A and facing to the right, the tile in front of
+you is marked B; if you are at C and facing down, the tile in front of you
+is marked D:
+        ...#
+        .#..
+        #...
+        ....
+...#.D.....#
+........#...
+B.#....#...A
+.....C....#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+It is possible for the next tile (after wrapping around) to be a wall; this
+still counts as there being a wall in front of you, and so movement stops
+before you actually wrap to the other side of the board.
+
+By drawing the last facing you had with an arrow on each tile you visit,
+the full path taken by the above example looks like this:
+        >>v#
+        .#v.
+        #.v.
+        ..v.
+...#...v..v#
+>>>v...>#.>>
+..#v...#....
+...>>>>v..#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+To finish providing the password to this strange input device, you need to
+determine numbers for your final row, column, and facing as your final
+position appears from the perspective of the original map. Rows start from
+1 at the top and count downward; columns start from 1 at the left and count
+rightward. (In the above example, row 1, column 1 refers to the empty space
+with no tile on it in the top-left corner.) Facing is 0 for right (>), 1
+for down (v), 2 for left (<), and 3 for up (^). The final password is
+the sum of 1000 times the row, 4 times the column, and the facing.
+
+In the above example, the final row is 6, the final column is 8, and the
+final facing is 0. So, the final password is 1000 * 6 + 4 * 8 + 0: 6032.
+
+Follow the path given in the monkeys' notes. What is the final password?
+
+--- Part Two ---
+As you reach the force field, you think you hear some Elves in the
+distance. Perhaps they've already arrived?
+
+You approach the strange input device, but it isn't quite what the monkeys
+drew in their notes. Instead, you are met with a large cube; each of its
+six faces is a square of 50x50 tiles.
+
+To be fair, the monkeys' map does have six 50x50 regions on it. If you were
+to carefully fold the map, you should be able to shape it into a cube!
+
+In the example above, the six (smaller, 4x4) faces of the cube are:
+        1111
+        1111
+        1111
+        1111
+222233334444
+222233334444
+222233334444
+222233334444
+        55556666
+        55556666
+        55556666
+        55556666
+
+You still start in the same position and with the same facing as before,
+but the wrapping rules are different. Now, if you would walk off the board,
+you instead proceed around the cube. From the perspective of the map, this
+can look a little strange. In the above example, if you are at A and move
+to the right, you would arrive at B facing down; if you are at C and move
+down, you would arrive at D facing up:
+        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#..A
+..#....#....
+.D........#.
+        ...#..B.
+        .....#..
+        .#......
+        ..C...#.
+
+Walls still block your path, even if they are on a different face of the
+cube. If you are at E facing up, your movement is blocked by the wall
+marked by the arrow:
+        ...#
+        .#..
+     -->#...
+        ....
+...#..E....#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+Using the same method of drawing the last facing you had with an arrow on
+each tile you visit, the full path taken by the above example now looks
+like this:
+        >>v#
+        .#v.
+        #.v.
+        ..v.
+...#..^...v#
+.>>>>>^.#.>>
+.^#....#....
+.^........#.
+        ...#..v.
+        .....#v.
+        .#v<<<<.
+        ..v...#.
+
+The final password is still calculated from your final position and facing
+from the perspective of the map. In this example, the final row is 5, the
+final column is 7, and the final facing is 3, so the final password is 1000
+* 5 + 4 * 7 + 3 = 5031.
+
+Fold the map into a cube, then follow the path given in the monkeys' notes.
+What is the final password?"#;
+
+#[derive(Default)]
+pub struct Day22 {}
+
+register!(Day22 {
+    day: 22,
+    title: "Monkey Map",
+    example: ("6032", "5031"),
+});
+
+impl Puzzle for Day22 {
+    fn part_one(&self, data: &str) -> Result<Answer> {
+        let (steps, board) = parse(data)?;
+        let (start_pos, start_dir) = start_params(&board)?;
+
+        let (end_pos, end_dir) = follow_steps(steps, board, start_pos, start_dir, simple_wrap);
+        Ok(password(end_pos, end_dir).into())
+    }
+
+    fn part_two(&self, data: &str) -> Result<Answer> {
+        let (steps, board) = parse(data)?;
+        let (start_pos, start_dir) = start_params(&board)?;
+
+        let cube = Cube::fold(&board)?;
+        let cube_wrap = |board: &Board, pos, dir, _| cube.wrap(board, pos, dir);
+
+        let (end_pos, end_dir) = follow_steps(steps, board, start_pos, start_dir, cube_wrap);
+        Ok(password(end_pos, end_dir).into())
+    }
+
+    fn parse(&self, data: &str) -> Option<Result<()>> {
+        Some(parse(data).map(drop))
+    }
+}
+
+/// Tiles of the map by their (column, row), both counted from 0
+pub type Board = SparseGrid<Tile>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Tile {
+    Space,
+    Wall,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Space => write!(f, "."),
+            Tile::Wall => write!(f, "#"),
+        }
+    }
+}
+
+/// Path to follow and the map it is followed on
+pub fn parse(data: &str) -> Result<(Vec<Step>, Board)> {
+    let (board, path) = data
+        .trim_end()
+        .rsplit_once('\n')
+        .context("Missing the path description")?;
+    let expected = "an open tile '.' or a wall '#'";
+    let board = SparseGrid::parse(board, &[' '], expected, |c| match c {
+        '.' => Some(Tile::Space),
+        '#' => Some(Tile::Wall),
+        _ => None,
+    })?;
+    Ok((Steps::from(path)?, board))
+}
+
+#[derive(Debug)]
+pub enum Step {
+    Move(i32),
+    TurnLeft,
+    TurnRight,
+}
+struct Steps;
+impl Steps {
+    fn from(data: &str) -> Result<Vec<Step>> {
+        let result = many1(alt((
+            i32.map(Step::Move),
+            alt((
+                char('L').map(|_| Step::TurnLeft),
+                char('R').map(|_| Step::TurnRight),
+            )),
+        )))(data) as IResult<&str, Vec<Step>>;
+        match result {
+            Ok(("", steps)) => Ok(steps),
+            Ok((rest, _)) => bail!("Unexpected {:?} in the path description", rest),
+            Err(_) => bail!("Invalid path description {:?}", data),
+        }
+    }
+}
+
+fn start_params(board: &Board) -> Result<(P2<i32>, P2<i32>)> {
+    let x_start = board
+        .iter()
+        .filter(|(pt, _)| pt.y == 0)
+        .map(|(pt, _)| pt.x)
+        .min()
+        .context("No open tile in the top row of the map")?;
+
+    Ok((P2::new(x_start, 0), P2::new(1, 0)))
+}
+
+fn password(pos: P2<i32>, dir: P2<i32>) -> i32 {
+    let (column, row) = pos.into();
+    let facing = match dir.into() {
+        (1, 0) => 0,
+        (0, 1) => 1,
+        (-1, 0) => 2,
+        (0, -1) => 3,
+        _ => unreachable!(),
+    };
+    1000 * (row + 1) + 4 * (column + 1) + facing
+}
+
+fn simple_wrap(board: &Board, pos: P2<i32>, dir: P2<i32>, bounds: P2<i32>) -> (P2<i32>, P2<i32>) {
+    let mut wrapped_pos = match dir.into() {
+        (-1, _) => P2::new(bounds.x, pos.y),
+        (1, _) => P2::new(0, pos.y),
+        (_, 1) => P2::new(pos.x, 0),
+        (_, -1) => P2::new(pos.x, bounds.y),
+        _ => unreachable!(),
+    };
+
+    while !board.contains(wrapped_pos) {
+        wrapped_pos += dir;
+    }
+    if board[wrapped_pos] == Tile::Wall {
+        return (pos, dir);
+    }
+    (wrapped_pos, dir)
+}
+
+type V3 = [i32; 3];
+
+fn dot(a: V3, b: V3) -> i32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn combine(terms: &[(V3, i32)]) -> V3 {
+    let mut sum = [0; 3];
+    for &(v, k) in terms {
+        for i in 0..3 {
+            sum[i] += v[i] * k;
+        }
+    }
+    sum
+}
+
+/// Where a face of the net ends up once folded: its outward normal and the directions its
+/// local x and y axes point to
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    normal: V3,
+    right: V3,
+    down: V3,
+}
+
+struct Cube {
+    size: i32,
+    faces: HashMap<P2<i32>, Face>,
+}
+
+impl Cube {
+    /// Folds the net face by face, starting from the top left one
+    fn fold(board: &Board) -> Result<Self> {
+        let size = ((board.len() / 6) as f64).sqrt() as i32;
+        if size == 0 || 6 * size * size != board.len() as i32 {
+            bail!("A map of {} tiles does not fold into a cube", board.len());
+        }
+
+        let face_of = |pt: P2<i32>| P2::new(pt.x / size, pt.y / size);
+        let net: HashSet<_> = board.positions().map(face_of).collect();
+        let start = board.positions().min_by_key(|pt| (pt.y, pt.x)).map(face_of);
+        let start = start.context("Empty map")?;
+
+        let first = Face {
+            normal: [0, 0, 1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        };
+        let mut faces = map![(start, first)];
+        let mut queue = queue![start];
+
+        while let Some(current) = queue.pop_front() {
+            let Face {
+                normal,
+                right,
+                down,
+            } = faces[&current];
+            let neg = |v: V3| v.map(|x| -x);
+
+            let neighbours = [
+                ((1, 0), [right, neg(normal), down]),
+                ((-1, 0), [neg(right), normal, down]),
+                ((0, 1), [down, right, neg(normal)]),
+                ((0, -1), [neg(down), right, normal]),
+            ];
+            for (step, [normal, right, down]) in neighbours {
+                let next = current + step.into();
+                if net.contains(&next) && !faces.contains_key(&next) {
+                    let face = Face {
+                        normal,
+                        right,
+                        down,
+                    };
+                    faces.insert(next, face);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let normals = faces.values().map(|f| f.normal).unique().count();
+        if net.len() != 6 || faces.len() != 6 || normals != 6 {
+            bail!("The map does not fold into a cube");
+        }
+        Ok(Self { size, faces })
+    }
+
+    /// Follows the cube surface over the edge, positions are tracked in 3D on a cube
+    /// spanning -size..=size so that tile centers keep integer coordinates
+    fn wrap(&self, board: &Board, pos: P2<i32>, dir: P2<i32>) -> (P2<i32>, P2<i32>) {
+        let s = self.size;
+        let corner = P2::new(pos.x / s, pos.y / s);
+        let from = self.faces[&corner];
+        let (i, j) = (pos.x - corner.x * s, pos.y - corner.y * s);
+
+        let exit = combine(&[(from.right, dir.x), (from.down, dir.y)]);
+        let point = combine(&[
+            (from.normal, s - 1),
+            (from.right, 2 * i + 1 - s),
+            (from.down, 2 * j + 1 - s),
+            (exit, 1),
+        ]);
+
+        let (&corner, to) = self
+            .faces
+            .iter()
+            .find(|(_, face)| face.normal == exit)
+            .unwrap();
+        let (i, j) = (
+            (dot(point, to.right) + s - 1) / 2,
+            (dot(point, to.down) + s - 1) / 2,
+        );
+        let wrapped_pos = P2::new(corner.x * s + i, corner.y * s + j);
+        let wrapped_dir = P2::new(-dot(from.normal, to.right), -dot(from.normal, to.down));
+
+        match board.get(wrapped_pos) {
+            Some(Tile::Wall) => (pos, dir),
+            _ => (wrapped_pos, wrapped_dir),
+        }
+    }
+}
+
+fn follow_steps(
+    steps: Vec<Step>,
+    board: Board,
+    mut pos: P2<i32>,
+    mut dir: P2<i32>,
+    wrapping_fn: impl Fn(&Board, P2<i32>, P2<i32>, P2<i32>) -> (P2<i32>, P2<i32>),
+) -> (P2<i32>, P2<i32>) {
+    use {Step::*, Tile::*};
+
+    let (_, bounds) = board.bounds().unwrap();
+
+    for step in steps {
+        match step {
+            Move(mut val) => {
+                while val > 0 {
+                    if let Some(&next_tile) = board.get(pos + dir) {
+                        if next_tile == Wall {
+                            break;
+                        }
+                        pos += dir
+                    } else {
+                        (pos, dir) = wrapping_fn(&board, pos, dir, bounds); // after changing dir - rotation change!
+                    }
+                    val -= 1;
+                }
+            }
+            #[rustfmt::skip]
+            TurnLeft => {
+                dir = match dir.into() {
+                    // should be dependent on previous dir state as well
+                    (-1,  0) => P2::new( 0,  1),
+                    ( 1,  0) => P2::new( 0, -1),
+                    ( 0, -1) => P2::new(-1,  0),
+                    ( 0,  1) => P2::new( 1,  0),
+                    _ => unreachable!(),
+                };
+            }
+            #[rustfmt::skip]
+            TurnRight => {
+                dir = match dir.into() {
+                    (-1,  0) => P2::new( 0, -1),
+                    ( 1,  0) => P2::new( 0,  1),
+                    ( 0, -1) => P2::new( 1,  0),
+                    ( 0,  1) => P2::new(-1,  0),
+                    _ => unreachable!(),
+                };
+            }
+        }
+    }
+    (pos, dir)
+}
@@ -0,0 +1,16 @@
+use crate::macros::*;
+use crate::puzzle::*;
+use crate::{ocr, Answer};
+use anyhow::{bail, Context, Result};
+
+const YEAR: u16 = 2022;
+
+/// Solves one part of a puzzle of this year with the official parameters
+pub fn solve(day: usize, part: Part, input: &str) -> Result<Answer> {
+    crate::solve(YEAR, day, part, input)
+}
+
+modules!(
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
@@ -0,0 +1,123 @@
+use crate::{registry, Answer};
+use anyhow::{bail, Context, Result};
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer,
+};
+use std::{collections::BTreeMap, fmt, path::Path};
+
+/// Known-correct answers for one day, either part may be missing
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Expected {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+    /// Puzzle parameters the answers were found with, if not the official ones
+    #[serde(default)]
+    pub params: BTreeMap<String, Recorded>,
+}
+
+/// A parameter value as written in the registry, bare numbers and booleans need no quotes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Recorded {
+    Bool(bool),
+    Number(i128),
+    Text(String),
+}
+
+impl<'de> Deserialize<'de> for Recorded {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(RecordedVisitor)
+    }
+}
+
+/// Visits the value itself, as an untagged enum would buffer it and lose i128 on the way
+struct RecordedVisitor;
+
+impl<'de> Visitor<'de> for RecordedVisitor {
+    type Value = Recorded;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a boolean, an integer or a string")
+    }
+
+    fn visit_bool<E: de::Error>(self, b: bool) -> Result<Recorded, E> {
+        Ok(Recorded::Bool(b))
+    }
+
+    fn visit_i64<E: de::Error>(self, n: i64) -> Result<Recorded, E> {
+        Ok(Recorded::Number(n.into()))
+    }
+
+    fn visit_u64<E: de::Error>(self, n: u64) -> Result<Recorded, E> {
+        Ok(Recorded::Number(n.into()))
+    }
+
+    fn visit_i128<E: de::Error>(self, n: i128) -> Result<Recorded, E> {
+        Ok(Recorded::Number(n))
+    }
+
+    fn visit_u128<E: de::Error>(self, n: u128) -> Result<Recorded, E> {
+        i128::try_from(n)
+            .map(Recorded::Number)
+            .map_err(|_| E::custom(format!("{} is too large", n)))
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Recorded, E> {
+        Ok(Recorded::Text(s.to_string()))
+    }
+}
+
+impl fmt::Display for Recorded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Recorded::Bool(b) => write!(f, "{}", b),
+            Recorded::Number(n) => write!(f, "{}", n),
+            Recorded::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Answers keyed by day
+pub type Answers = BTreeMap<usize, Expected>;
+
+/// Reads a registry with one `[dayNN]` table per day holding `part_one` and `part_two`
+pub fn load_answers(path: impl AsRef<Path>) -> Result<Answers> {
+    let path = path.as_ref();
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not read answers file '{}'", path.display()))?;
+    parse_answers(&text).with_context(|| format!("Invalid answers file '{}'", path.display()))
+}
+
+pub fn parse_answers(text: &str) -> Result<Answers> {
+    let tables: BTreeMap<String, Expected> = toml::from_str(text)?;
+
+    let mut answers = Answers::new();
+    for (key, expected) in tables {
+        let day = key
+            .strip_prefix("day")
+            .and_then(|n| n.parse().ok())
+            .filter(|day| (1..=25).contains(day));
+        match day {
+            Some(day) => answers.insert(day, expected),
+            None => bail!("Expected a table named day01..day25, found [{}]", key),
+        };
+    }
+    Ok(answers)
+}
+
+/// Answers to the examples, as registered along with the puzzles of the year
+pub fn example_answers(year: u16) -> Answers {
+    let puzzles = registry().into_iter().filter(|p| p.info.year == year);
+    let text = |answer: &str| Some(Answer::from(answer));
+    puzzles
+        .map(|p| {
+            let [one, two] = p.example;
+            let expected = Expected {
+                part_one: text(one),
+                part_two: text(two),
+                ..Default::default()
+            };
+            (p.info.day, expected)
+        })
+        .collect()
+}
@@ -1,62 +1,54 @@
-pub fn load_input(day: usize) -> &'static str {
-    match day {
-        1 => include_str!("../assets/inputs/day01.txt"),
-        2 => include_str!("../assets/inputs/day02.txt"),
-        3 => include_str!("../assets/inputs/day03.txt"),
-        4 => include_str!("../assets/inputs/day04.txt"),
-        5 => include_str!("../assets/inputs/day05.txt"),
-        6 => include_str!("../assets/inputs/day06.txt"),
-        7 => include_str!("../assets/inputs/day07.txt"),
-        8 => include_str!("../assets/inputs/day08.txt"),
-        9 => include_str!("../assets/inputs/day09.txt"),
-        10 => include_str!("../assets/inputs/day10.txt"),
-        11 => include_str!("../assets/inputs/day11.txt"),
-        12 => include_str!("../assets/inputs/day12.txt"),
-        13 => include_str!("../assets/inputs/day13.txt"),
-        14 => include_str!("../assets/inputs/day14.txt"),
-        15 => include_str!("../assets/inputs/day15.txt"),
-        16 => include_str!("../assets/inputs/day16.txt"),
-        17 => include_str!("../assets/inputs/day17.txt"),
-        18 => include_str!("../assets/inputs/day18.txt"),
-        19 => include_str!("../assets/inputs/day19.txt"),
-        20 => include_str!("../assets/inputs/day20.txt"),
-        21 => include_str!("../assets/inputs/day21.txt"),
-        22 => include_str!("../assets/inputs/day22.txt"),
-        23 => include_str!("../assets/inputs/day23.txt"),
-        24 => include_str!("../assets/inputs/day24.txt"),
-        25 => include_str!("../assets/inputs/day25.txt"),
-        _ => unreachable!(),
-    }
+use anyhow::{Context, Result};
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+pub fn assets_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("assets")
+}
+
+/// Assets of one year, holding its `inputs` and `examples` directories
+pub fn year_dir(year: u16) -> PathBuf {
+    assets_dir().join(year.to_string())
+}
+
+pub fn load_input(year: u16, day: usize) -> Result<String> {
+    load_from(year_dir(year).join("inputs"), day)
+}
+
+pub fn load_example(year: u16, day: usize) -> Result<String> {
+    load_from(year_dir(year).join("examples"), day)
+}
+
+/// Reads `dayNN.txt` from the given directory
+pub fn load_from(dir: impl AsRef<Path>, day: usize) -> Result<String> {
+    load_file(dir.as_ref().join(format!("day{:02}.txt", day)))
+}
+
+pub fn load_file(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    log::info!("Reading input file '{}'", path.display());
+    std::fs::read_to_string(path)
+        .map(|input| normalize(&input))
+        .with_context(|| format!("Could not read input file '{}'", path.display()))
+}
+
+pub fn load_stdin() -> Result<String> {
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .context("Could not read input from stdin")?;
+    Ok(normalize(&input))
 }
 
-#[allow(unused)]
-pub fn load_example(day: usize) -> &'static str {
-    match day {
-        1 => include_str!("../assets/examples/day01.txt"),
-        2 => include_str!("../assets/examples/day02.txt"),
-        3 => include_str!("../assets/examples/day03.txt"),
-        4 => include_str!("../assets/examples/day04.txt"),
-        5 => include_str!("../assets/examples/day05.txt"),
-        6 => include_str!("../assets/examples/day06.txt"),
-        7 => include_str!("../assets/examples/day07.txt"),
-        8 => include_str!("../assets/examples/day08.txt"),
-        9 => include_str!("../assets/examples/day09.txt"),
-        10 => include_str!("../assets/examples/day10.txt"),
-        11 => include_str!("../assets/examples/day11.txt"),
-        12 => include_str!("../assets/examples/day12.txt"),
-        13 => include_str!("../assets/examples/day13.txt"),
-        14 => include_str!("../assets/examples/day14.txt"),
-        15 => include_str!("../assets/examples/day15.txt"),
-        16 => include_str!("../assets/examples/day16.txt"),
-        17 => include_str!("../assets/examples/day17.txt"),
-        18 => include_str!("../assets/examples/day18.txt"),
-        19 => include_str!("../assets/examples/day19.txt"),
-        20 => include_str!("../assets/examples/day20.txt"),
-        21 => include_str!("../assets/examples/day21.txt"),
-        22 => include_str!("../assets/examples/day22.txt"),
-        23 => include_str!("../assets/examples/day23.txt"),
-        24 => include_str!("../assets/examples/day24.txt"),
-        25 => include_str!("../assets/examples/day25.txt"),
-        _ => unreachable!(),
+/// Turns Windows line endings into `\n`, drops the whitespace at the end of every line and
+/// ends the input with a single newline, since editors add and remove these at will
+pub fn normalize(input: &str) -> String {
+    let mut normalized = String::with_capacity(input.len());
+    for line in input.trim_end().lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
     }
+    normalized
 }
@@ -0,0 +1,105 @@
+use crate::Puzzle;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// Timings of repeated runs in nanoseconds
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub max: u64,
+}
+
+impl Stats {
+    fn from(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let nanos = |d: &Duration| d.as_nanos() as u64;
+        Self {
+            min: samples.first().map_or(0, nanos),
+            median: samples.get(samples.len() / 2).map_or(0, nanos),
+            max: samples.last().map_or(0, nanos),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Measurement {
+    /// Parsing alone, for puzzles that parse ahead of solving
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse: Option<Stats>,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+/// Measurements of the puzzles of one year, keyed by day
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Baseline {
+    pub year: u16,
+    pub days: BTreeMap<usize, Measurement>,
+}
+
+impl Baseline {
+    pub fn new(year: u16) -> Self {
+        Self {
+            year,
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+pub fn measure(puzzle: &dyn Puzzle, input: &str, runs: usize) -> Result<Measurement> {
+    let parse = match puzzle.parse(input) {
+        Some(parsed) => {
+            parsed?;
+            Some(sample(runs, || puzzle.parse(input).unwrap_or(Ok(())))?)
+        }
+        None => None,
+    };
+
+    Ok(Measurement {
+        parse,
+        part_one: sample(runs, || puzzle.part_one(input))?,
+        part_two: sample(runs, || puzzle.part_two(input))?,
+    })
+}
+
+fn sample<T>(runs: usize, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        black_box(f()?);
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::from(samples))
+}
+
+/// Reads a baseline saved earlier, which must hold measurements of the puzzles of `year`
+pub fn load_baseline(path: impl AsRef<Path>, year: u16) -> Result<Baseline> {
+    let path = path.as_ref();
+    let json = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not read baseline '{}'", path.display()))?;
+    let baseline: Baseline = serde_json::from_str(&json)
+        .with_context(|| format!("Invalid baseline '{}'", path.display()))?;
+    if baseline.year != year {
+        bail!(
+            "Baseline '{}' measured the {} puzzles, not the {} ones",
+            path.display(),
+            baseline.year,
+            year
+        );
+    }
+    Ok(baseline)
+}
+
+pub fn save_baseline(path: impl AsRef<Path>, baseline: &Baseline) -> Result<()> {
+    let path = path.as_ref();
+    let json = serde_json::to_string_pretty(baseline)?;
+    std::fs::write(path, json)
+        .with_context(|| format!("Could not write baseline '{}'", path.display()))
+}
@@ -0,0 +1,350 @@
+use crate::puzzle::Expected;
+use anyhow::{bail, Result};
+use pt::P2;
+use std::{
+    collections::HashMap,
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// Steps to the 4 orthogonal neighbours, clockwise from up, with rows growing downwards
+pub const ORTHOGONAL: [P2<i32>; 4] = [
+    P2 { x: 0, y: -1 },
+    P2 { x: 1, y: 0 },
+    P2 { x: 0, y: 1 },
+    P2 { x: -1, y: 0 },
+];
+
+/// Steps to all 8 neighbours, clockwise from up
+pub const ADJACENT: [P2<i32>; 8] = [
+    P2 { x: 0, y: -1 },
+    P2 { x: 1, y: -1 },
+    P2 { x: 1, y: 0 },
+    P2 { x: 1, y: 1 },
+    P2 { x: 0, y: 1 },
+    P2 { x: -1, y: 1 },
+    P2 { x: -1, y: 0 },
+    P2 { x: -1, y: -1 },
+];
+
+/// Rectangle of cells stored row after row, addressed by (column, row) from the top left
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` by `height` cells set to `fill`, without any row or column when
+    /// either is 0
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let (width, height) = match width * height {
+            0 => (0, 0),
+            _ => (width, height),
+        };
+        let cells = vec![fill; width * height];
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Reads one cell per character with `f`, which rejects a character by returning `None`,
+    /// the error then points at it as not being what was `expected`
+    pub fn parse(data: &str, expected: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let (mut cells, mut width, mut height) = (vec![], None, 0);
+        for (y, line) in data.lines().enumerate() {
+            let mut columns = 0;
+            for (x, c) in line.chars().enumerate() {
+                match f(c) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(Expected::at(x + 1, expected).on_line(y + 1)),
+                }
+                columns += 1;
+            }
+            let width = *width.get_or_insert(columns);
+            if columns != width {
+                let expected = Expected::at(columns.min(width) + 1, format!("{} columns", width));
+                return Err(expected.on_line(y + 1));
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            _ => bail!("Empty grid"),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: P2<i32>) -> bool {
+        (0..self.width as i32).contains(&p.x) && (0..self.height as i32).contains(&p.y)
+    }
+
+    fn offset(&self, p: P2<i32>) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    pub fn get(&self, p: P2<i32>) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: P2<i32>) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Positions of all cells, row after row
+    pub fn positions(&self) -> impl Iterator<Item = P2<i32>> {
+        let width = self.width as i32;
+        (0..self.height as i32).flat_map(move |y| (0..width).map(move |x| P2::new(x, y)))
+    }
+
+    /// Cells with their positions, row after row
+    pub fn iter(&self) -> impl Iterator<Item = (P2<i32>, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row after row, that satisfies `f`
+    pub fn find(&self, mut f: impl FnMut(&T) -> bool) -> Option<P2<i32>> {
+        self.iter().find(|(_, cell)| f(cell)).map(|(p, _)| p)
+    }
+
+    /// Cells of row `y` from left to right, if the grid is that high
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // An empty grid has no cells to chunk, the width only has to be valid
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Cells of column `x` from top to bottom, if the grid is that wide
+    pub fn column(&self, x: usize) -> Option<impl DoubleEndedIterator<Item = &T>> {
+        (x < self.width).then(|| self.cells[x..].iter().step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).filter_map(|x| self.column(x))
+    }
+
+    /// Orthogonal neighbours of `p` inside the grid
+    pub fn neighbours4(&self, p: P2<i32>) -> impl Iterator<Item = P2<i32>> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .map(move |step| p + step)
+            .filter(|&n| self.contains(n))
+    }
+
+    /// Orthogonal and diagonal neighbours of `p` inside the grid
+    pub fn neighbours8(&self, p: P2<i32>) -> impl Iterator<Item = P2<i32>> + '_ {
+        ADJACENT
+            .into_iter()
+            .map(move |step| p + step)
+            .filter(|&n| self.contains(n))
+    }
+
+    /// Positions from `p`, excluded, to the edge of the grid in steps of `step`
+    pub fn ray(&self, p: P2<i32>, step: P2<i32>) -> impl Iterator<Item = P2<i32>> + '_ {
+        std::iter::successors(Some(p + step), move |&n| Some(n + step))
+            .take_while(|&n| self.contains(n))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<P2<i32>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: P2<i32>) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is outside of the {}x{} grid", p, width, height))
+    }
+}
+
+impl<T> IndexMut<P2<i32>> for Grid<T> {
+    fn index_mut(&mut self, p: P2<i32>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is outside of the {}x{} grid", p, width, height))
+    }
+}
+
+/// Draws the cells row by row, each row ending with a newline
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Unbounded grid holding only the cells that are set, addressed like [`Grid`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<P2<i32>, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Like [`Grid::parse`], but lines may differ in length and the characters in `blank`
+    /// leave their cell empty
+    pub fn parse(
+        data: &str,
+        blank: &[char],
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let mut grid = Self::new();
+        for (y, line) in data.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if blank.contains(&c) {
+                    continue;
+                }
+                match f(c) {
+                    Some(cell) => grid.insert(P2::new(x as i32, y as i32), cell),
+                    None => return Err(Expected::at(x + 1, expected).on_line(y + 1)),
+                };
+            }
+        }
+        Ok(grid)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, p: P2<i32>) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn get(&self, p: P2<i32>) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: P2<i32>) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    /// Sets the cell at `p`, returning what it held before
+    pub fn insert(&mut self, p: P2<i32>, cell: T) -> Option<T> {
+        self.cells.insert(p, cell)
+    }
+
+    pub fn remove(&mut self, p: P2<i32>) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    /// Positions of the cells that are set, in no particular order
+    pub fn positions(&self) -> impl Iterator<Item = P2<i32>> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (P2<i32>, &T)> {
+        self.cells.iter().map(|(&p, cell)| (p, cell))
+    }
+
+    /// Top left and bottom right corners of the rectangle around the cells that are set
+    pub fn bounds(&self) -> Option<(P2<i32>, P2<i32>)> {
+        let mut positions = self.positions();
+        let first = positions.next()?;
+        Some(positions.fold((first, first), |(min, max), p| {
+            let min = P2::new(min.x.min(p.x), min.y.min(p.y));
+            let max = P2::new(max.x.max(p.x), max.y.max(p.y));
+            (min, max)
+        }))
+    }
+
+    /// Orthogonal neighbours of `p`, set or not
+    pub fn neighbours4(&self, p: P2<i32>) -> impl Iterator<Item = P2<i32>> {
+        ORTHOGONAL.into_iter().map(move |step| p + step)
+    }
+
+    /// Orthogonal and diagonal neighbours of `p`, set or not
+    pub fn neighbours8(&self, p: P2<i32>) -> impl Iterator<Item = P2<i32>> {
+        ADJACENT.into_iter().map(move |step| p + step)
+    }
+}
+
+impl<T> Index<P2<i32>> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, p: P2<i32>) -> &T {
+        self.get(p).unwrap_or_else(|| panic!("No cell at {:?}", p))
+    }
+}
+
+impl<T> FromIterator<(P2<i32>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (P2<i32>, T)>>(iter: I) -> Self {
+        let cells = iter.into_iter().collect();
+        Self { cells }
+    }
+}
+
+impl<T> Extend<(P2<i32>, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (P2<i32>, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter)
+    }
+}
+
+/// Draws the rectangle around the cells that are set, leaving the others blank
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min, max) = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.get(P2::new(x, y)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, " ")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
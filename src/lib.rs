@@ -1,7 +1,14 @@
 pub mod advent;
+mod answer;
+pub mod answers;
 mod assets;
+pub mod bench;
+pub mod grid;
 mod macros;
+pub mod ocr;
 mod puzzle;
+pub mod search;
 
+pub use answer::Answer;
 pub use assets::*;
 pub use puzzle::*;
@@ -1,9 +1,10 @@
+/// Parses a token, failing with a `ParseError`
 macro_rules! parse {
     ($s: ident as $t: ty) => {
-        $s.parse::<$t>().expect("Parsing error")
+        $crate::puzzle::ParseError::parse::<$t>(&$s)
     };
     ($s: expr) => {
-        $s.parse().expect("Parsing error")
+        $crate::puzzle::ParseError::parse(&$s)
     };
     ($s: ident else $d: expr) => {
         $s.parse().unwrap_or($d)
@@ -11,23 +12,27 @@ macro_rules! parse {
 }
 pub(crate) use parse;
 
+/// Regex compiled on first use and kept for the rest of the process, failing with a
+/// `ParseError` if the pattern does not compile
 macro_rules! re {
-    ($($s: literal),*) => {
-        regex::Regex::new(concat![$($s),*]).unwrap()
-    };
+    ($($s: literal),*) => {{
+        static CACHE: std::sync::OnceLock<Result<regex::Regex, regex::Error>> =
+            std::sync::OnceLock::new();
+        $crate::puzzle::ParseError::regex(&CACHE, concat![$($s),*])
+    }};
 }
 pub(crate) use re;
 
+/// Capture groups of a regex match as a tuple, failing with a `ParseError`
 macro_rules! captures {
     ($s: expr, $re: expr) => {{
         use itertools::Itertools;
-        $re.captures($s)
-            .unwrap()
-            .iter()
-            .skip(1)
-            .map(|cap| cap.unwrap().as_str())
-            .collect_tuple()
-            .unwrap()
+        let (s, re): (&str, &regex::Regex) = ($s, &$re);
+        $crate::puzzle::ParseError::captures(re, s).and_then(|caps| {
+            caps.into_iter()
+                .collect_tuple()
+                .ok_or_else(|| $crate::puzzle::ParseError::missing_field(re, s))
+        })
     }};
 }
 pub(crate) use captures;
@@ -63,9 +68,82 @@ macro_rules! queue {
 }
 pub(crate) use queue;
 
+/// Implements `Puzzle::set_param` for the listed parameter fields
+macro_rules! params {
+    ($($field: ident),*) => {
+        fn set_param(&mut self, key: &str, value: &str) -> anyhow::Result<()> {
+            match key {
+                $(stringify!($field) => self.$field = parse!(value)?,)*
+                _ => anyhow::bail!(
+                    "Unknown parameter {:?}, expected one of: {}",
+                    key,
+                    [$(stringify!($field)),*].join(", ")
+                ),
+            }
+            Ok(())
+        }
+    };
+}
+pub(crate) use params;
+
+/// Submits a puzzle of the current module's `YEAR` with its `DESCRIPTION` to the registry,
+/// see `Registration`
+macro_rules! register {
+    ($puzzle: ident {
+        day: $day: literal,
+        title: $title: literal,
+        example: ($one: literal, $two: literal),
+        $(example_puzzle: $example: expr,)?
+    }) => {
+        inventory::submit! {
+            $crate::puzzle::Registration {
+                info: $crate::puzzle::PuzzleInfo {
+                    year: YEAR,
+                    day: $day,
+                    title: $title,
+                    description: DESCRIPTION,
+                },
+                name: stringify!($puzzle),
+                module: module_path!(),
+                example: [$one, $two],
+                build: || Box::new($puzzle::default()),
+                build_example: register!(@example $puzzle $(, $example)?),
+            }
+        }
+    };
+    (@example $puzzle: ident) => {
+        || Box::new($puzzle::default())
+    };
+    (@example $puzzle: ident, $example: expr) => {
+        || Box::new($example)
+    };
+}
+pub(crate) use register;
+
 macro_rules! modules {
     ($($m: ident),*) => {$(
         pub mod $m;
     )*};
 }
 pub(crate) use modules;
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::ParseErrorKind;
+
+    fn digits() -> &'static regex::Regex {
+        re!(r"\d+").unwrap()
+    }
+
+    #[test]
+    fn re_compiles_each_pattern_once() {
+        assert!(std::ptr::eq(digits(), digits()));
+    }
+
+    #[test]
+    fn re_reports_invalid_patterns() {
+        let err = re!(r"(\d+").unwrap_err();
+        assert!(matches!(err.kind, ParseErrorKind::InvalidPattern(_)));
+        assert_eq!(r"(\d+", err.pattern);
+    }
+}
@@ -1,23 +1,738 @@
-use aoc::{dispatch, load_input};
-use clap::Parser;
+use anyhow::{anyhow, bail, Context, Result};
+use aoc::{
+    answers,
+    bench::{self, Baseline, Stats},
+    find, load_example, load_file, load_from, load_input, load_stdin, registry, year_dir, years,
+    Answer, Puzzle, PuzzleInfo,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use itertools::Itertools;
+use log::LevelFilter;
+#[cfg(feature = "parallel")]
+use rayon::join;
+use serde::Serialize;
+use std::{
+    io::Write,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 
-/// Solver of 2022 Advent of Code Puzzles
+/// Solver of Advent of Code Puzzles
 #[derive(Parser)]
 #[command(author, version, about)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
-    /// Advent day number (1..=25)
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Advent days to run: a day (1..=25), a range (3..=7 or 3..8), a list (1,5,9) or "all"
+    #[arg(value_parser = parse_days, required = true)]
+    days: Option<Days>,
+
+    /// Run only the given part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Override a puzzle parameter, e.g. --param rounds=5000 (repeatable)
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Plain)]
+    format: Format,
+
+    /// Log what the solvers do to stderr, in more detail with every -v
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Log every step of the solutions of a day of the selected year (repeatable)
+    #[arg(long, value_name = "DAY", value_parser = parse_day, global = true)]
+    trace: Vec<usize>,
+
+    #[command(flatten)]
+    source: Source,
+}
+
+impl Args {
+    /// Year the command runs the puzzles of
+    fn year(&self) -> u16 {
+        match &self.command {
+            Some(Command::Bench(bench)) => bench.source.year,
+            Some(Command::Verify(verify)) => verify.source.year,
+            Some(Command::Describe(describe)) => describe.year,
+            Some(Command::Check(check)) => check.year,
+            None => self.source.year,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Tables for people to read
+    Plain,
+    /// An array of records with the day, part, answer and time of every run
+    Json,
+    /// The same records as comma separated values, with a header
+    Csv,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Time repeated runs of each puzzle, optionally against a saved baseline
+    Bench(BenchArgs),
+    /// Check the answers of each puzzle against a registry of known-correct ones
+    Verify(VerifyArgs),
+    /// Print the title, link and text of a puzzle
+    Describe(DescribeArgs),
+    /// Report the problems in an input file, or solve the puzzle if there are none
+    Check(CheckArgs),
+}
+
+#[derive(clap::Args)]
+struct CheckArgs {
+    /// Advent day of the puzzle (1..=25)
+    #[arg(value_parser = parse_day)]
+    day: usize,
+
+    /// Input file to check, "-" reads from stdin
+    file: PathBuf,
+
+    /// Advent year of the puzzle
+    #[arg(long, default_value_t = latest_year())]
+    year: u16,
+}
+
+#[derive(clap::Args)]
+struct DescribeArgs {
+    /// Advent day of the puzzle (1..=25)
+    #[arg(value_parser = parse_day)]
+    day: usize,
+
+    /// Advent year of the puzzle
+    #[arg(long, default_value_t = latest_year())]
+    year: u16,
+}
+
+#[derive(clap::Args)]
+struct BenchArgs {
+    /// Advent days to benchmark
+    #[arg(value_parser = parse_days, default_value = "all")]
+    days: Days,
+
+    /// Number of timed runs per stage
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Save the measurements as a JSON baseline
+    #[arg(long, value_name = "PATH")]
+    save: Option<PathBuf>,
+
+    /// Compare the medians against a JSON baseline saved earlier
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+
+    /// Override a puzzle parameter, e.g. --param rounds=5000 (repeatable)
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+
+    #[command(flatten)]
+    source: Source,
+}
+
+#[derive(clap::Args)]
+struct VerifyArgs {
+    /// Advent days to verify
+    #[arg(value_parser = parse_days, default_value = "all")]
+    days: Days,
+
+    /// TOML file with the known answers [default: answers.toml in the inputs directory]
+    #[arg(long, value_name = "PATH")]
+    answers: Option<PathBuf>,
+
+    #[command(flatten)]
+    source: Source,
+}
+
+#[derive(clap::Args)]
+struct Source {
+    /// Advent year of the puzzles
+    #[arg(long, default_value_t = latest_year())]
+    year: u16,
+
+    /// Run on the example input from assets/<YEAR>/examples
+    #[arg(long, conflicts_with_all = ["inputs", "input"])]
+    example: bool,
+
+    /// Directory with the puzzle inputs [default: assets/<YEAR>/inputs]
+    #[arg(long, value_name = "DIR")]
+    inputs: Option<PathBuf>,
+
+    /// Input file for a single day, "-" reads from stdin
+    #[arg(long, value_name = "PATH", conflicts_with = "inputs")]
+    input: Option<PathBuf>,
+}
+
+fn latest_year() -> u16 {
+    years().last().copied().unwrap_or_default()
+}
+
+#[derive(Clone)]
+struct Days(Vec<usize>);
+
+fn parse_days(s: &str) -> Result<Days, String> {
+    if s == "all" {
+        return Ok(Days((1..=25).collect()));
+    }
+
+    let mut days = vec![];
+    for item in s.split(',') {
+        let range = match item.split_once("..") {
+            Some((start, end)) => match end.strip_prefix('=') {
+                Some(end) => parse_day(start)?..=parse_day(end)?,
+                None => parse_day(start)?..=parse_range_end(end)? - 1,
+            },
+            None => parse_day(item).map(|day| day..=day)?,
+        };
+        if range.is_empty() {
+            return Err(format!("Range {} contains no days", item));
+        }
+        days.extend(range);
+    }
+    Ok(Days(days))
+}
+
+fn parse_day(s: &str) -> Result<usize, String> {
+    match s.trim().parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("{:?} is not an advent day number (1..=25)", s)),
+    }
+}
+
+/// End of an exclusive range of days, which may be one past the last day
+fn parse_range_end(s: &str) -> Result<usize, String> {
+    match s.trim().parse() {
+        Ok(end @ 1..=26) => Ok(end),
+        _ => Err(format!(
+            "{:?} is not the end of a range of advent days (..26 or ..=25)",
+            s
+        )),
+    }
+}
+
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((key, value)) => Ok((key.trim().to_string(), value.trim().to_string())),
+        None => Err(format!("Expected KEY=VALUE, found {:?}", s)),
+    }
+}
+
+struct Solution {
+    answer: Result<Answer>,
+    time: Duration,
+}
+
+fn timed(f: impl FnOnce() -> Result<Answer>) -> Solution {
+    let start = Instant::now();
+    let answer = panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|_| Err(anyhow!("Solver panicked")));
+    Solution {
+        answer,
+        time: start.elapsed(),
+    }
+}
+
+fn load(source: &Source, day: usize) -> Result<String> {
+    match (&source.input, &source.inputs) {
+        (Some(path), _) if path.as_os_str() == "-" => load_stdin(),
+        (Some(path), _) => load_file(path),
+        _ if source.example => load_example(source.year, day),
+        (None, Some(dir)) => load_from(dir, day),
+        (None, None) => load_input(source.year, day),
+    }
+}
+
+/// Creates the puzzle for a day, set up for the example if it runs on one, with its
+/// parameters overridden
+fn configure(source: &Source, day: usize, params: &[(String, String)]) -> Result<Box<dyn Puzzle>> {
+    let registration = find(source.year, day)?;
+    let mut puzzle = match source.example {
+        true => (registration.build_example)(),
+        false => (registration.build)(),
+    };
+    for (key, value) in params {
+        log::info!("Day {}: setting {} to {}", day, key, value);
+        puzzle
+            .set_param(key, value)
+            .with_context(|| format!("Invalid parameter {}={} for day {}", key, value, day))?;
+    }
+    Ok(puzzle)
+}
+
+fn run(args: &Args, day: usize) -> Result<[Option<Solution>; 2]> {
+    let puzzle = configure(&args.source, day, &args.params)?;
+    let input = load(&args.source, day)?;
+
+    let selected = |part| args.part.is_none_or(|p| p == part);
+    let (one, two) = join(
+        || selected(1).then(|| timed(|| puzzle.part_one(&input))),
+        || selected(2).then(|| timed(|| puzzle.part_two(&input))),
+    );
+    Ok([one, two])
+}
+
+/// Runs every day, on a thread pool with the `parallel` feature, keeping the results in
+/// the order of the days
+fn run_all(args: &Args, days: &[usize]) -> Vec<(usize, Result<[Option<Solution>; 2]>)> {
+    #[cfg(feature = "parallel")]
+    use rayon::prelude::*;
+    #[cfg(feature = "parallel")]
+    let days = days.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let days = days.iter();
+
+    days.map(|&day| (day, run(args, day))).collect()
+}
+
+/// Runs both parts one after the other without the `parallel` feature
+#[cfg(not(feature = "parallel"))]
+fn join<A, B>(a: impl FnOnce() -> A, b: impl FnOnce() -> B) -> (A, B) {
+    (a(), b())
+}
+
+/// Sends warnings to stderr, with more messages for every -v and all of them from the
+/// solutions of the traced days
+fn init_logging(args: &Args) {
+    let level = match args.verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    let mut builder = env_logger::Builder::new();
+    builder.filter_level(LevelFilter::Warn);
+    builder.filter_module(env!("CARGO_CRATE_NAME"), level);
+    let year = args.year();
+    for registration in registry() {
+        let info = &registration.info;
+        if info.year == year && args.trace.contains(&info.day) {
+            builder.filter_module(registration.module, LevelFilter::Trace);
+        }
+    }
+    builder.format(|buf, record| {
+        writeln!(
+            buf,
+            "[{} {}] {}",
+            record.level(),
+            record.target(),
+            record.args()
+        )
+    });
+    builder.init();
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    init_logging(&args);
+    match &args.command {
+        Some(Command::Bench(bench)) => return run_bench(bench),
+        Some(Command::Verify(verify)) => return run_verify(verify),
+        Some(Command::Describe(describe)) => return run_describe(describe),
+        Some(Command::Check(check)) => return run_check(check),
+        None => (),
+    }
+
+    let days = args.days.as_ref().map_or(&[][..], |days| &days.0);
+    if days.len() > 1 && args.source.input.is_some() {
+        bail!("--input can only be used with a single day");
+    }
+    match (args.format, days) {
+        (Format::Plain, &[day]) => print_day(&find(args.source.year, day)?.info, run(&args, day)?),
+        (Format::Plain, _) => print_table(&args, days),
+        (format, _) => print_records(format, &records(&args, days)),
+    }
+}
+
+/// Outcome of one part of a puzzle, as printed by --format json and csv
+#[derive(Serialize)]
+struct Record {
+    year: u16,
     day: usize,
+    part: u8,
+    answer: Option<Answer>,
+    error: Option<String>,
+    /// Time the part took to solve, absent if it could not be run
+    time_ns: Option<u64>,
+}
+
+fn records(args: &Args, days: &[usize]) -> Vec<Record> {
+    let record = |day, part, answer, error, time_ns| Record {
+        year: args.source.year,
+        day,
+        part,
+        answer,
+        error,
+        time_ns,
+    };
+
+    let mut records = vec![];
+    for (day, solutions) in run_all(args, days) {
+        match solutions {
+            Ok(solutions) => {
+                for (part, solution) in (1..).zip(solutions) {
+                    let Some(solution) = solution else { continue };
+                    let time = Some(solution.time.as_nanos() as u64);
+                    records.push(match solution.answer {
+                        Ok(answer) => record(day, part, Some(answer), None, time),
+                        Err(err) => record(day, part, None, Some(format!("{:#}", err)), time),
+                    });
+                }
+            }
+            Err(err) => {
+                let parts = (1..=2).filter(|&part| args.part.is_none_or(|p| p == part));
+                for part in parts {
+                    records.push(record(day, part, None, Some(format!("{:#}", err)), None));
+                }
+            }
+        }
+    }
+    records
+}
+
+fn print_records(format: Format, records: &[Record]) -> Result<()> {
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(records)?),
+        _ => {
+            println!("year,day,part,answer,error,time_ns");
+            for record in records {
+                let answer = record.answer.as_ref().map(Answer::to_string);
+                println!(
+                    "{},{},{},{},{},{}",
+                    record.year,
+                    record.day,
+                    record.part,
+                    csv_field(answer.as_deref().unwrap_or_default()),
+                    csv_field(record.error.as_deref().unwrap_or_default()),
+                    record.time_ns.map(|t| t.to_string()).unwrap_or_default()
+                );
+            }
+        }
+    }
+
+    let failures = records.iter().filter(|r| r.error.is_some()).count();
+    if failures > 0 {
+        bail!("{} puzzle runs failed", failures);
+    }
+    Ok(())
+}
+
+/// Quotes a value holding separators, quotes or line breaks
+fn csv_field(value: &str) -> String {
+    match value.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
+
+fn print_day(info: &PuzzleInfo, solutions: [Option<Solution>; 2]) -> Result<()> {
+    let (day, title) = (info.day, info.title);
+    println!(
+        "{:*^60}",
+        format!(" Advent of Code {} - Day {}: {} ", info.year, day, title)
+    );
+    for (part, solution) in ["one", "two"].iter().zip(solutions) {
+        if let Some(solution) = solution {
+            let answer = solution
+                .answer
+                .with_context(|| format!("Day {} part {} failed", day, part))?;
+            match answer.is_multiline() {
+                true => println!("Part {}:\n{}", part, answer),
+                false => println!("Part {} {:.>51}", part, format!(" {}", answer)),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_table(args: &Args, days: &[usize]) -> Result<()> {
+    println!("{:*^83}", format!(" Advent of Code {} ", args.source.year));
+    println!(
+        "{:>3} | {:<24} | {:>10} | {:<24} | {:>10}",
+        "Day", "Part one", "Time", "Part two", "Time"
+    );
+    println!("{:-<83}", "");
+
+    let (mut total, mut failures, mut images) = (Duration::ZERO, 0, vec![]);
+    for (day, solutions) in run_all(args, days) {
+        let solutions = match solutions {
+            Ok(solutions) => solutions,
+            Err(err) => {
+                println!("{:>3} | {:#}", day, err);
+                failures += 1;
+                continue;
+            }
+        };
+
+        let mut cells = vec![];
+        for (part, solution) in ["one", "two"].iter().zip(solutions) {
+            let Some(solution) = solution else {
+                cells.push(("-".to_string(), "-".to_string()));
+                continue;
+            };
+            total += solution.time;
+            let answer = match solution.answer {
+                Ok(answer) if answer.is_multiline() => {
+                    images.push((day, part, answer));
+                    "(see below)".to_string()
+                }
+                Ok(answer) => answer.to_string(),
+                Err(err) => {
+                    failures += 1;
+                    format!("error: {:#}", err)
+                }
+            };
+            cells.push((answer, format!("{:.2?}", solution.time)));
+        }
+        println!(
+            "{:>3} | {:<24} | {:>10} | {:<24} | {:>10}",
+            day, cells[0].0, cells[0].1, cells[1].0, cells[1].1
+        );
+    }
+
+    println!("{:-<83}", "");
+    println!("{:<72} {:>10.2?}", "Total", total);
+
+    for (day, part, image) in images {
+        println!("\nDay {} part {}:\n{}", day, part, image);
+    }
+
+    if failures > 0 {
+        bail!("{} puzzle runs failed", failures);
+    }
+    Ok(())
 }
 
-fn main() {
-    let day = Args::parse().day;
-    assert!(
-        (1..=25).contains(&day),
-        "Advent day number out of range (1..=25)"
+fn run_bench(args: &BenchArgs) -> Result<()> {
+    if args.days.0.len() > 1 && args.source.input.is_some() {
+        bail!("--input can only be used with a single day");
+    }
+    let baseline = args
+        .baseline
+        .as_ref()
+        .map(|path| bench::load_baseline(path, args.source.year))
+        .transpose()?;
+
+    println!("{:*^83}", format!(" Benchmark - {} runs ", args.runs));
+    println!(
+        "{:>3} | {:<8} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10}",
+        "Day", "Stage", "Min", "Median", "Max", "Baseline", "Change"
     );
+    println!("{:-<83}", "");
+
+    let mut measurements = Baseline::new(args.source.year);
+    for &day in &args.days.0 {
+        let puzzle = configure(&args.source, day, &args.params)?;
+        let input = load(&args.source, day)?;
+        let measurement = bench::measure(puzzle.as_ref(), &input, args.runs as usize)
+            .with_context(|| format!("Day {} failed", day))?;
+
+        let previous = baseline.as_ref().and_then(|b| b.days.get(&day));
+        let stages = [
+            ("parse", measurement.parse, previous.and_then(|p| p.parse)),
+            (
+                "part one",
+                Some(measurement.part_one),
+                previous.map(|p| p.part_one),
+            ),
+            (
+                "part two",
+                Some(measurement.part_two),
+                previous.map(|p| p.part_two),
+            ),
+        ];
+        for (stage, stats, previous) in stages {
+            if let Some(stats) = stats {
+                print_stage(day, stage, stats, previous);
+            }
+        }
+        measurements.days.insert(day, measurement);
+    }
+
+    if let Some(path) = &args.save {
+        bench::save_baseline(path, &measurements)?;
+        println!("\nBaseline saved to '{}'", path.display());
+    }
+    Ok(())
+}
+
+fn print_stage(day: usize, stage: &str, stats: Stats, previous: Option<Stats>) {
+    let time = |nanos| format!("{:.2?}", Duration::from_nanos(nanos));
+    let (baseline, change) = match previous {
+        Some(prev) if prev.median > 0 => {
+            let change = (stats.median as f64 / prev.median as f64 - 1.0) * 100.0;
+            (time(prev.median), format!("{:+.1}%", change))
+        }
+        _ => ("-".to_string(), "-".to_string()),
+    };
+    println!(
+        "{:>3} | {:<8} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10}",
+        day,
+        stage,
+        time(stats.min),
+        time(stats.median),
+        time(stats.max),
+        baseline,
+        change
+    );
+}
+
+fn run_verify(args: &VerifyArgs) -> Result<()> {
+    if args.days.0.len() > 1 && args.source.input.is_some() {
+        bail!("--input can only be used with a single day");
+    }
+    let path = match (&args.answers, &args.source.inputs) {
+        (Some(path), _) => Some(path.clone()),
+        _ if args.source.example => None,
+        (None, Some(dir)) => Some(dir.join("answers.toml")),
+        (None, None) => Some(year_dir(args.source.year).join("inputs/answers.toml")),
+    };
+    let (answers, origin) = match &path {
+        Some(path) => (
+            answers::load_answers(path)?,
+            format!("'{}'", path.display()),
+        ),
+        None => (
+            answers::example_answers(args.source.year),
+            "the registry".into(),
+        ),
+    };
+
+    println!("{:*^83}", " Verify ");
+    println!("{:>3} | {:<8} | {:<10} | Details", "Day", "Part", "Status");
+    println!("{:-<83}", "");
+
+    let (mut passed, mut regressions, mut failures, mut unverified) = (0, 0, 0, 0);
+    for &day in &args.days.0 {
+        let Some(expected) = answers.get(&day) else {
+            println!(
+                "{:>3} | {:<8} | {:<10} | no recorded answers",
+                day, "-", "unverified"
+            );
+            unverified += 1;
+            continue;
+        };
+        let input = match load(&args.source, day) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{:>3} | {:<8} | {:<10} | {:#}", day, "-", "FAIL", err);
+                failures += 1;
+                continue;
+            }
+        };
+
+        let params = expected.params.iter();
+        let params = params
+            .map(|(k, v)| (k.clone(), v.to_string()))
+            .collect_vec();
+        let puzzle = match configure(&args.source, day, &params) {
+            Ok(puzzle) => puzzle,
+            Err(err) => {
+                println!("{:>3} | {:<8} | {:<10} | {:#}", day, "-", "FAIL", err);
+                failures += 1;
+                continue;
+            }
+        };
+        let parts = [
+            ("one", &expected.part_one, timed(|| puzzle.part_one(&input))),
+            ("two", &expected.part_two, timed(|| puzzle.part_two(&input))),
+        ];
+        for (part, expected, solution) in parts {
+            let (status, details) = match (solution.answer, expected) {
+                (Err(err), _) => {
+                    failures += 1;
+                    ("FAIL", format!("{:#}", err))
+                }
+                (Ok(answer), None) => {
+                    unverified += 1;
+                    (
+                        "unverified",
+                        format!("got {}", summary(&answer.to_string())),
+                    )
+                }
+                (Ok(answer), Some(expected)) if answer.matches(&expected.to_string()) => {
+                    passed += 1;
+                    ("pass", summary(&answer.to_string()))
+                }
+                (Ok(answer), Some(expected)) => {
+                    regressions += 1;
+                    let expected = summary(&expected.to_string());
+                    let answer = summary(&answer.to_string());
+                    let details = format!("got {}, expected {}", answer, expected);
+                    ("REGRESSION", details)
+                }
+            };
+            let part = format!("part {}", part);
+            println!("{:>3} | {:<8} | {:<10} | {}", day, part, status, details);
+        }
+    }
+
+    println!("{:-<83}", "");
+    println!(
+        "{} passed, {} regressions, {} failed, {} unverified",
+        passed, regressions, failures, unverified
+    );
+
+    if regressions + failures > 0 {
+        bail!("Answers do not match those in {}", origin);
+    }
+    Ok(())
+}
+
+/// Keeps multi-line answers from breaking the table
+fn summary(answer: &str) -> String {
+    match answer.trim().contains('\n') {
+        true => "(multi-line)".to_string(),
+        false => answer.trim().to_string(),
+    }
+}
+
+fn run_describe(args: &DescribeArgs) -> Result<()> {
+    let info = &find(args.year, args.day)?.info;
+    println!(
+        "{:*^80}",
+        format!(
+            " Advent of Code {} - Day {}: {} ",
+            info.year, info.day, info.title
+        )
+    );
+    println!("{}\n", info.url());
+    println!("{}", info.description);
+    Ok(())
+}
+
+fn run_check(args: &CheckArgs) -> Result<()> {
+    let registration = find(args.year, args.day)?;
+    let input = match args.file.as_os_str() == "-" {
+        true => load_stdin()?,
+        false => load_file(&args.file)?,
+    };
+
+    let puzzle = (registration.build)();
+    let diagnostics = puzzle.validate(&input);
+    if !diagnostics.is_empty() {
+        for diagnostic in &diagnostics {
+            println!("{}\n", diagnostic);
+        }
+        let problems = match diagnostics.len() {
+            1 => "1 problem".to_string(),
+            n => format!("{} problems", n),
+        };
+        bail!("Found {} in '{}'", problems, args.file.display());
+    }
 
-    let (puzzle, input) = (dispatch(day), load_input(day));
-    println!("{:*^60}", format!(" Advent of Code 2022 - Day {} ", day));
-    println!("Part one {:.>51}", format!(" {}", puzzle.part_one(input)));
-    println!("Part two {:.>51}", format!(" {}", puzzle.part_two(input)));
+    let solutions = [
+        Some(timed(|| puzzle.part_one(&input))),
+        Some(timed(|| puzzle.part_two(&input))),
+    ];
+    print_day(&registration.info, solutions)
 }
@@ -0,0 +1,68 @@
+use anyhow::{bail, ensure, Result};
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+/// Letters are separated by one blank column
+const SPACING: usize = GLYPH_WIDTH + 1;
+
+/// The 4x6 font advent of code draws its letters with, row by row
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads the letters drawn with `#` and `.` in the rows of an image
+pub fn recognize<S: AsRef<str>>(rows: &[S]) -> Result<String> {
+    let rows: Vec<&[u8]> = rows.iter().map(|row| row.as_ref().as_bytes()).collect();
+    ensure!(
+        rows.len() == GLYPH_HEIGHT,
+        "Letters are {} rows high, the image has {}",
+        GLYPH_HEIGHT,
+        rows.len()
+    );
+    let width = rows[0].len();
+    ensure!(
+        rows.iter().all(|row| row.len() == width),
+        "The rows of the image differ in width"
+    );
+
+    (0..width)
+        .step_by(SPACING)
+        .map(|x| {
+            let glyph: Vec<&[u8]> = rows
+                .iter()
+                .map(|row| &row[x..width.min(x + GLYPH_WIDTH)])
+                .collect();
+            match FONT
+                .iter()
+                .find(|(_, rows)| rows.iter().zip(&glyph).all(|(a, b)| a.as_bytes() == *b))
+            {
+                Some((letter, _)) => Ok(*letter),
+                None => bail!(
+                    "Unrecognized letter at column {}:\n{}",
+                    x,
+                    glyph
+                        .iter()
+                        .map(|row| String::from_utf8_lossy(row))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                ),
+            }
+        })
+        .collect()
+}
@@ -1,37 +1,366 @@
-use crate::advent::*;
-
-pub trait Puzzle {
-    fn part_one(&self, data: &'static str) -> String;
-    fn part_two(&self, data: &'static str) -> String;
-}
-
-pub fn dispatch(day: usize) -> Box<dyn Puzzle> {
-    match day {
-        1 => Box::new(day01::Day01 {}),
-        2 => Box::new(day02::Day02 {}),
-        3 => Box::new(day03::Day03 {}),
-        4 => Box::new(day04::Day04 {}),
-        5 => Box::new(day05::Day05 {}),
-        6 => Box::new(day06::Day06 {}),
-        7 => Box::new(day07::Day07 {}),
-        8 => Box::new(day08::Day08 {}),
-        9 => Box::new(day09::Day09 {}),
-        10 => Box::new(day10::Day10 {}),
-        11 => Box::new(day11::Day11 {}),
-        12 => Box::new(day12::Day12 {}),
-        13 => Box::new(day13::Day13 {}),
-        14 => Box::new(day14::Day14 {}),
-        15 => Box::new(day15::Day15 {}),
-        16 => Box::new(day16::Day16 {}),
-        17 => Box::new(day17::Day17 {}),
-        18 => Box::new(day18::Day18 {}),
-        19 => Box::new(day19::Day19 {}),
-        20 => Box::new(day20::Day20 {}),
-        21 => Box::new(day21::Day21 {}),
-        22 => Box::new(day22::Day22 {}),
-        23 => Box::new(day23::Day23 {}),
-        24 => Box::new(day24::Day24 {}),
-        25 => Box::new(day25::Day25 {}),
-        _ => unreachable!(),
+use crate::{normalize, Answer};
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use std::{fmt, str::FromStr, sync::OnceLock};
+
+/// A puzzle solver; solvers are shared between threads when running in parallel
+pub trait Puzzle: Send + Sync {
+    fn part_one(&self, data: &str) -> Result<Answer>;
+    fn part_two(&self, data: &str) -> Result<Answer>;
+
+    /// Only parses the input, for puzzles with a parsing step shared by both parts
+    fn parse(&self, _data: &str) -> Option<Result<()>> {
+        None
+    }
+
+    /// Problems with the input that keep it from being solved, found by parsing it
+    fn validate(&self, data: &str) -> Vec<Diagnostic> {
+        match self.parse(data) {
+            Some(Err(err)) => vec![Diagnostic::new(data, &err)],
+            _ => vec![],
+        }
+    }
+
+    /// Overrides one of the puzzle parameters, which default to the official values
+    fn set_param(&mut self, key: &str, _value: &str) -> Result<()> {
+        bail!("Unknown parameter {:?}, this puzzle has none", key)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(part: u8) -> Result<Self> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => bail!("Puzzles have parts 1 and 2, not {}", part),
+        }
+    }
+}
+
+/// What is known about a puzzle, independent of its solution
+pub struct PuzzleInfo {
+    pub year: u16,
+    pub day: usize,
+    pub title: &'static str,
+    /// The text of both parts of the puzzle
+    pub description: &'static str,
+}
+
+impl PuzzleInfo {
+    pub fn url(&self) -> String {
+        format!("https://adventofcode.com/{}/day/{}", self.year, self.day)
+    }
+}
+
+/// A puzzle solution and what is known about it, submitted by its module with `register!`
+pub struct Registration {
+    pub info: PuzzleInfo,
+    pub name: &'static str,
+    /// Path of the module with the solution, which its log messages are sent from
+    pub module: &'static str,
+    /// Answers to the example input, solved by the `build_example` puzzle
+    pub example: [&'static str; 2],
+    /// Creates the puzzle with the official parameters
+    pub build: fn() -> Box<dyn Puzzle>,
+    /// Creates the puzzle with the parameters the example was made for
+    pub build_example: fn() -> Box<dyn Puzzle>,
+}
+
+inventory::collect!(Registration);
+
+/// Every registered puzzle, ordered by year and day
+pub fn registry() -> Vec<&'static Registration> {
+    let mut puzzles = inventory::iter::<Registration>
+        .into_iter()
+        .collect::<Vec<_>>();
+    puzzles.sort_by_key(|p| (p.info.year, p.info.day));
+    puzzles
+}
+
+/// Years with registered puzzles, in ascending order
+pub fn years() -> Vec<u16> {
+    let mut years = registry()
+        .into_iter()
+        .map(|p| p.info.year)
+        .collect::<Vec<_>>();
+    years.dedup();
+    years
+}
+
+pub fn find(year: u16, day: usize) -> Result<&'static Registration> {
+    inventory::iter::<Registration>
+        .into_iter()
+        .find(|p| p.info.year == year && p.info.day == day)
+        .with_context(|| format!("No solution for day {} of {}", day, year))
+}
+
+/// Creates the puzzle of the given year and day with the official parameters
+pub fn dispatch(year: u16, day: usize) -> Result<Box<dyn Puzzle>> {
+    find(year, day).map(|p| (p.build)())
+}
+
+/// Solves one part of the puzzle of the given year and day with the official parameters,
+/// normalizing the input like the asset loaders do
+pub fn solve(year: u16, day: usize, part: Part, input: &str) -> Result<Answer> {
+    let puzzle = dispatch(year, day)?;
+    let input = normalize(input);
+    match part {
+        Part::One => puzzle.part_one(&input),
+        Part::Two => puzzle.part_two(&input),
+    }
+}
+
+/// Parses every line of the input with `f`, pointing at the offending line on failure
+pub(crate) fn parse_lines<'a, T, E: Into<anyhow::Error>>(
+    data: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T, E>,
+) -> Result<Vec<T>> {
+    data.lines()
+        .enumerate()
+        .map(|(n, line)| f(line).map_err(Into::into).context(Line(n + 1)))
+        .collect()
+}
+
+/// Checks every line of the input with `f`, reporting all the lines it rejects
+pub(crate) fn validate_lines<T, E: Into<anyhow::Error>>(
+    data: &str,
+    mut f: impl FnMut(&str) -> Result<T, E>,
+) -> Vec<Diagnostic> {
+    data.lines()
+        .enumerate()
+        .filter_map(|(n, line)| f(line).map_err(Into::into).context(Line(n + 1)).err())
+        .map(|err| Diagnostic::new(data, &err))
+        .collect()
+}
+
+/// Context of errors in a line of the input, counted from 1
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Line(pub usize);
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Line {}", self.0)
+    }
+}
+
+/// Error pointing at the column of a line where the input differs from what was expected
+#[derive(Debug)]
+pub(crate) struct Expected {
+    pub column: usize,
+    pub what: String,
+}
+
+impl Expected {
+    pub fn at(column: usize, what: impl Into<String>) -> Self {
+        let what = what.into();
+        Self { column, what }
+    }
+
+    /// For parsers reading the lines themselves, rather than with `parse_lines`
+    pub fn on_line(self, line: usize) -> anyhow::Error {
+        anyhow::Error::new(self).context(Line(line))
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Expected {} at column {}", self.what, self.column)
+    }
+}
+
+impl std::error::Error for Expected {}
+
+/// Error of the `parse!`, `captures!` and `re!` helpers, with the pattern and the text that
+/// did not fit it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Regex the text had to match, or the type it was parsed as
+    pub pattern: String,
+    /// Text that failed to parse
+    pub text: String,
+    /// Capture group of the regex, counted from 1, when the error is about one
+    pub field: Option<usize>,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The text does not match the regex
+    NoMatch,
+    /// The capture group `field` is not part of the match
+    MissingField,
+    /// The text is not a value of the type, for the given reason
+    Invalid(String),
+    /// The regex itself does not compile, for the given reason
+    InvalidPattern(String),
+}
+
+impl ParseError {
+    /// Parses `text` as a `T`, which is what `parse!` expands to
+    pub(crate) fn parse<T: FromStr>(text: &str) -> Result<T, Self>
+    where
+        T::Err: fmt::Display,
+    {
+        text.parse().map_err(|err: T::Err| Self {
+            pattern: std::any::type_name::<T>().to_string(),
+            text: text.to_string(),
+            field: None,
+            kind: ParseErrorKind::Invalid(err.to_string()),
+        })
+    }
+
+    /// Matches `text` with `re` and returns all of its capture groups, which is what
+    /// `captures!` expands to
+    pub(crate) fn captures<'a>(re: &Regex, text: &'a str) -> Result<Vec<&'a str>, Self> {
+        let error = |field, kind| Self {
+            pattern: re.as_str().to_string(),
+            text: text.to_string(),
+            field,
+            kind,
+        };
+        let caps = re
+            .captures(text)
+            .ok_or_else(|| error(None, ParseErrorKind::NoMatch))?;
+        let fields = caps.iter().enumerate().skip(1);
+        fields
+            .map(|(n, cap)| cap.map(|cap| cap.as_str()).ok_or(n))
+            .collect::<Result<_, _>>()
+            .map_err(|n| error(Some(n), ParseErrorKind::MissingField))
+    }
+
+    /// Error for a `captures!` whose tuple does not have one element per capture group
+    pub(crate) fn missing_field(re: &Regex, text: &str) -> Self {
+        Self {
+            pattern: re.as_str().to_string(),
+            text: text.to_string(),
+            field: Some(re.captures_len()),
+            kind: ParseErrorKind::MissingField,
+        }
+    }
+
+    /// Compiles `pattern` the first time and returns the same regex afterwards, which is
+    /// what `re!` expands to with one `cache` per call site
+    pub(crate) fn regex(
+        cache: &'static OnceLock<Result<Regex, regex::Error>>,
+        pattern: &str,
+    ) -> Result<&'static Regex, Self> {
+        cache
+            .get_or_init(|| Regex::new(pattern))
+            .as_ref()
+            .map_err(|err| Self {
+                pattern: pattern.to_string(),
+                text: pattern.to_string(),
+                field: None,
+                kind: ParseErrorKind::InvalidPattern(err.to_string()),
+            })
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::NoMatch => {
+                write!(f, "Expected {:?} to match '{}'", self.text, self.pattern)
+            }
+            ParseErrorKind::MissingField => write!(
+                f,
+                "Missing capture group {} of '{}' in {:?}",
+                self.field.unwrap_or_default(),
+                self.pattern,
+                self.text
+            ),
+            ParseErrorKind::Invalid(reason) => {
+                write!(f, "Invalid token {:?}: {}", self.text, reason)
+            }
+            ParseErrorKind::InvalidPattern(reason) => {
+                write!(f, "Invalid regex '{}': {}", self.pattern, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Problem with a puzzle input, with its position when it is limited to one line
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Line of the input, counted from 1
+    pub line: Option<usize>,
+    /// Column of the line, counted from 1
+    pub column: Option<usize>,
+    /// What was expected there, or what is wrong with the input
+    pub message: String,
+    /// Part of the line around the column
+    pub snippet: String,
+    /// Column of the first character of the snippet, counted from 0
+    offset: usize,
+}
+
+impl Diagnostic {
+    /// Characters of the line shown on either side of the column
+    const CONTEXT: usize = 30;
+
+    /// Points at the line and column recorded by the `Line` and `Expected` parts of the error,
+    /// or at the text a `ParseError` could not read
+    pub(crate) fn new(data: &str, err: &anyhow::Error) -> Self {
+        let line = err.downcast_ref::<Line>().map(|line| line.0);
+        let text = line
+            .and_then(|n| data.lines().nth(n - 1))
+            .unwrap_or_default();
+        let expected = err.downcast_ref::<Expected>();
+        let column = match expected {
+            Some(expected) => Some(expected.column),
+            None => err
+                .downcast_ref::<ParseError>()
+                .and_then(|e| text.find(&e.text))
+                .filter(|_| line.is_some())
+                .map(|i| text[..i].chars().count() + 1),
+        };
+        let message = match expected {
+            Some(expected) => format!("expected {}", expected.what),
+            None => {
+                let line = line.map(|n| Line(n).to_string());
+                let causes = err.chain().map(|cause| cause.to_string());
+                let causes = causes.filter(|cause| Some(cause) != line.as_ref());
+                causes.collect::<Vec<_>>().join(": ")
+            }
+        };
+
+        let offset = column.map_or(0, |c| c.saturating_sub(Self::CONTEXT + 1));
+        let snippet = text.chars().skip(offset).take(2 * Self::CONTEXT).collect();
+        Self {
+            line,
+            column,
+            message,
+            snippet,
+            offset,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            _ => (),
+        }
+        write!(f, "{}", self.message)?;
+
+        if self.line.is_some() {
+            write!(f, "\n  | {}", self.snippet)?;
+            if let Some(column) = self.column {
+                write!(f, "\n  | {:>1$}", "^", column - self.offset)?;
+            }
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,184 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Outcome of a search: the cheapest cost of every state it reached and the steps taking
+/// there, up to the goal when one was found
+#[derive(Clone, Debug)]
+pub struct Search<S, C> {
+    costs: HashMap<S, C>,
+    parents: HashMap<S, S>,
+    /// First state satisfying the goal, the search stops there
+    pub goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new() -> Self {
+        Self {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// Cost of the cheapest path from a source to `state`, if it was reached
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// Costs of all the states reached, which are final for the states that were expanded
+    pub fn costs(&self) -> &HashMap<S, C> {
+        &self.costs
+    }
+
+    /// States from a source to `state`, both included
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        self.costs.get(state)?;
+        let mut path = vec![state.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path(self.goal.as_ref()?)
+    }
+}
+
+/// Breadth first search from all the `sources` at once, where every step costs 1
+///
+/// Explores every reachable state when `goal` is never satisfied.
+pub fn bfs<S, I>(
+    sources: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for source in sources {
+        search.costs.insert(source.clone(), 0);
+        queue.push_back(source);
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        let cost = search.costs[&state] + 1;
+        for next in successors(&state) {
+            if !search.costs.contains_key(&next) {
+                search.costs.insert(next.clone(), cost);
+                search.parents.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Cheapest first search from all the `sources` at once, with `successors` giving the cost
+/// of every step
+pub fn dijkstra<S, C, I>(
+    sources: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(sources, successors, |_| C::default(), goal)
+}
+
+/// Like [`dijkstra`], but guided towards the goal by `heuristic`, which must never
+/// overestimate the remaining cost nor drop by more than the cost of a step
+pub fn astar<S, C, I>(
+    sources: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new();
+    let mut queue = BinaryHeap::new();
+    for source in sources {
+        let (cost, priority) = (C::default(), heuristic(&source));
+        search.costs.insert(source.clone(), cost);
+        queue.push(Queued {
+            priority,
+            cost,
+            state: source,
+        });
+    }
+
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        if cost > search.costs[&state] {
+            continue;
+        }
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            if search.costs.get(&next).is_none_or(|&known| cost < known) {
+                search.costs.insert(next.clone(), cost);
+                search.parents.insert(next.clone(), state.clone());
+                queue.push(Queued {
+                    priority: cost + heuristic(&next),
+                    cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    search
+}
+
+/// State waiting in the queue, popped from the max-heap lowest priority first
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
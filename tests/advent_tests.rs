@@ -1,69 +1,426 @@
-use aoc::{dispatch, load_example};
-use concat_idents::concat_idents;
+use aoc::{
+    advent::y2022,
+    answers::{parse_answers, Recorded},
+    bench, dispatch,
+    grid::{Grid, SparseGrid},
+    load_example, normalize, registry,
+    search::{astar, bfs, dijkstra},
+    solve, Answer, ParseError, ParseErrorKind, Part, Puzzle,
+};
+use pt::{pt, P2};
+use serde::{de::IntoDeserializer, Deserialize};
 
-fn extract_day(name: &str) -> usize {
-    name.strip_prefix("day").unwrap().parse().unwrap()
+#[test]
+fn every_day_is_registered() {
+    let days = registry()
+        .into_iter()
+        .filter(|p| p.info.year == 2022)
+        .map(|p| p.info.day);
+    assert_eq!((1..=25).collect::<Vec<_>>(), days.collect::<Vec<_>>());
 }
 
-macro_rules! advent_tests {
-    ($($name:ident: ($expect_one:literal, $expect_two:literal),)*) => {
-    $(
-        concat_idents!(test_name = $name, _part_one {
-            #[test]
-            fn test_name() {
-                let day = extract_day(stringify!($name));
-                let (puzzle, input) = (dispatch(day), load_example(day));
+#[test]
+fn descriptions_match_titles() {
+    for registration in registry() {
+        let info = &registration.info;
+        let heading = format!("--- Day {}: {} ---", info.day, info.title);
+        assert!(info.description.starts_with(&heading), "{}", heading);
+    }
+}
 
-                let result = puzzle.part_one(input);
-                assert_eq!($expect_one, result, "expected: {}, result: {}", $expect_one, result);
+#[test]
+fn examples() {
+    let mut failures = vec![];
+    for registration in registry() {
+        let (year, day) = (registration.info.year, registration.info.day);
+        let (puzzle, input) = (
+            (registration.build_example)(),
+            load_example(year, day).unwrap(),
+        );
+
+        let results = [puzzle.part_one(&input), puzzle.part_two(&input)];
+        for ((part, expected), result) in
+            ["one", "two"].iter().zip(registration.example).zip(results)
+        {
+            match result {
+                Ok(result) if result.matches(expected) => (),
+                Ok(result) => failures.push(format!(
+                    "{} day {} part {}: expected: {}, result: {}",
+                    year, day, part, expected, result
+                )),
+                Err(err) => failures.push(format!("{} day {} part {}: {:#}", year, day, part, err)),
             }
-        });
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
 
-        concat_idents!(test_name = $name, _part_two {
-            #[test]
-            fn test_name() {
-                let day = extract_day(stringify!($name));
-                let (puzzle, input) = (dispatch(day), load_example(day));
+#[test]
+fn examples_with_windows_line_endings() {
+    let mut failures = vec![];
+    for registration in registry() {
+        let (year, day) = (registration.info.year, registration.info.day);
+        let example = load_example(year, day).unwrap();
+        let variants = [
+            example.replace('\n', "\r\n"),
+            example.replace('\n', " \r\n") + "\r\n\r\n",
+            example.trim_end().replace('\n', "\r\n"),
+        ];
 
-               let result = puzzle.part_two(input);
-                assert_eq!($expect_two, result, "expected: {}, result: {}", $expect_two, result);
+        let puzzle = (registration.build_example)();
+        for (n, variant) in variants.iter().enumerate() {
+            let input = normalize(variant);
+            let results = [puzzle.part_one(&input), puzzle.part_two(&input)];
+            for (expected, result) in registration.example.iter().zip(results) {
+                match result {
+                    Ok(result) if result.matches(expected) => (),
+                    Ok(result) => failures.push(format!(
+                        "{} day {} variant {}: expected: {}, result: {}",
+                        year, day, n, expected, result
+                    )),
+                    Err(err) => {
+                        failures.push(format!("{} day {} variant {}: {:#}", year, day, n, err))
+                    }
+                }
             }
-        });
-    )*
+        }
     }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn normalize_line_endings() {
+    assert_eq!("a\n  b\n\nc\n", normalize("a \r\n  b\t\r\n\r\nc\r\n\r\n"));
+    assert_eq!("", normalize(" \r\n"));
+
+    let input = "1=-0-2\r\n12111\r\n";
+    assert_eq!(
+        Answer::from("1-111="),
+        solve(2022, 25, Part::One, input).unwrap()
+    );
+}
+
+#[test]
+fn day20_mixes_a_single_number() {
+    assert_eq!(
+        Answer::from(0),
+        solve(2022, 20, Part::One, "0\r\n").unwrap()
+    );
+    assert_eq!(Answer::from(0), solve(2022, 20, Part::Two, "0\n").unwrap());
+}
+
+#[test]
+fn day21_reports_divisions_by_zero() {
+    let error = |part, input| solve(2022, 21, part, input).unwrap_err().to_string();
+
+    let input = "root: aaaa / bbbb\naaaa: 4\nbbbb: zero * humn\nzero: 0\nhumn: 5\n";
+    assert_eq!("Monkey root cannot divide 4 by 0", error(Part::One, input));
+    let input = "root: aaaa + bbbb\naaaa: 4\nbbbb: humn * zero\nzero: 0\nhumn: 5\n";
+    assert_eq!("Monkey bbbb cannot divide 4 by 0", error(Part::Two, input));
+    let input = "root: aaaa + bbbb\naaaa: 0\nbbbb: four / humn\nfour: 4\nhumn: 5\n";
+    assert_eq!("Monkey bbbb cannot divide 4 by 0", error(Part::Two, input));
+}
+
+#[test]
+fn parameters_override_the_official_values() {
+    let input = load_example(2022, 15).unwrap();
+    let mut puzzle = dispatch(2022, 15).unwrap();
+    puzzle.set_param("row", "10").unwrap();
+    puzzle.set_param("bound", "20").unwrap();
+    assert_eq!(Answer::from(26), puzzle.part_one(&input).unwrap());
+    assert_eq!(Answer::from(56000011), puzzle.part_two(&input).unwrap());
+
+    assert!(puzzle.set_param("nope", "1").is_err());
+    assert!(puzzle.set_param("row", "abc").is_err());
+    assert!(puzzle.set_param("row", "").is_err());
+
+    // Part two without the key and with a single mix is part one
+    let input = load_example(2022, 20).unwrap();
+    let mut puzzle = dispatch(2022, 20).unwrap();
+    puzzle.set_param("key", "1").unwrap();
+    puzzle.set_param("mixes", "1").unwrap();
+    assert_eq!(Answer::from(3), puzzle.part_two(&input).unwrap());
+    assert!(puzzle.set_param("mixes", "-1").is_err());
+}
+
+#[test]
+fn day15_scans_the_row_it_is_given() {
+    // Small coordinates say nothing about which row and bound the puzzle asks for
+    let input = "Sensor at x=0, y=0: closest beacon is at x=2, y=0\n";
+    let puzzle = y2022::day15::Day15 { row: 1, bound: 3 };
+    assert_eq!(Answer::from(3), puzzle.part_one(input).unwrap());
+    assert_eq!(Answer::from(12000000), puzzle.part_two(input).unwrap());
+
+    let puzzle = y2022::day15::Day15 { row: 0, bound: 1 };
+    assert_eq!(Answer::from(4), puzzle.part_one(input).unwrap());
+    assert!(puzzle.part_two(input).is_err());
+}
+
+#[test]
+fn parameters_are_read_from_the_command_line() {
+    let aoc = |args: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["15", "--example", "--format", "json"])
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    let output = aoc(&["--param", "row=10", "--param", " bound = 20 "]);
+    assert!(output.status.success());
+    let records: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let answers = records
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["answer"].as_i64());
+    assert_eq!(vec![Some(26), Some(56000011)], answers.collect::<Vec<_>>());
+
+    assert!(!aoc(&["--param", "row"]).status.success());
+    assert!(!aoc(&["--param", "nope=1"]).status.success());
+    assert!(!aoc(&["--param", "row=abc"]).status.success());
+}
+
+#[test]
+fn day_ranges_are_read_from_the_command_line() {
+    let days = |days: &str| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args([days, "--example", "--format", "json", "--part", "1"])
+            .output()
+            .unwrap();
+        let records: serde_json::Value = serde_json::from_slice(&output.stdout).ok()?;
+        let days = records.as_array()?.iter().map(|r| r["day"].as_u64());
+        days.collect::<Option<Vec<_>>>()
+    };
+
+    assert_eq!(Some(vec![24, 25]), days("24..26"));
+    assert_eq!(Some(vec![24, 25]), days("24..=25"));
+    assert_eq!(Some(vec![1, 24, 25]), days("1,24..26"));
+    assert_eq!(None, days("24..27"));
+    assert_eq!(None, days("24..=26"));
+    assert_eq!(None, days("25..25"));
+}
+
+#[test]
+fn answers_record_every_kind_of_parameter() {
+    let text = concat!(
+        "[day10]\n",
+        "part_one = 13140\n",
+        "params = { raw = true }\n",
+        "[day11]\n",
+        "params = { rounds_one = 20, rounds_two = -1, note = \"x\" }\n",
+    );
+    let answers = parse_answers(text).unwrap();
+
+    let raw = &answers[&10].params["raw"];
+    assert_eq!(&Recorded::Bool(true), raw);
+    assert_eq!("true", raw.to_string());
+    let params = &answers[&11].params;
+    assert_eq!(Recorded::Number(20), params["rounds_one"]);
+    assert_eq!(Recorded::Number(-1), params["rounds_two"]);
+    assert_eq!(Recorded::Text("x".to_string()), params["note"]);
+    let large: Result<_, serde::de::value::Error> =
+        Recorded::deserialize(i128::MAX.into_deserializer());
+    assert_eq!(Recorded::Number(i128::MAX), large.unwrap());
+
+    let mut puzzle = dispatch(2022, 10).unwrap();
+    puzzle.set_param("raw", &raw.to_string()).unwrap();
 }
 
-advent_tests! {
-    day01: ("24000", "45000"),
-    day02: ("15", "12"),
-    day03: ("157", "70"),
-    day04: ("2", "4"),
-    // day05: ("CMZ", "MCD"),
-    day06: ("7", "19"),
-    day07: ("95437", "24933642"),
-    day08: ("21", "8"),
-    day09: ("13", "1"),
-    day10: ("13140", "
-##..##..##..##..##..##..##..##..##..##..
-###...###...###...###...###...###...###.
-####....####....####....####....####....
-#####.....#####.....#####.....#####.....
-######......######......######......####
-#######.......#######.......#######.....
-"),
-    day11: ("10605", "2713310158"),
-    day12: ("31", "29"),
-    day13: ("13", "140"),
-    day14: ("24", "93"),
-    // day15: ("26", "56000011"),
-    // day16: ("1651", "1707"),
-    day17: ("3068", "1514285714288"),
-    day18: ("64", "58"),
-    // day19: ("33", "62"),
-    day20: ("3", "1623178306"),
-    day21: ("152", "301"),
-    // day22: ("6032", "5031"),
-    day23: ("110", "20"),
-    day24: ("18", "54"),
-    day25: ("2=-1=0", "The End!"),
+#[test]
+fn baselines_only_compare_the_same_year() {
+    let path = std::env::temp_dir().join(format!("aoc-baseline-{}.json", std::process::id()));
+    let mut baseline = bench::Baseline::new(2022);
+    let puzzle = dispatch(2022, 1).unwrap();
+    let input = load_example(2022, 1).unwrap();
+    let measurement = bench::measure(puzzle.as_ref(), &input, 1).unwrap();
+    baseline.days.insert(1, measurement);
+    bench::save_baseline(&path, &baseline).unwrap();
+
+    let loaded = bench::load_baseline(&path, 2022);
+    let other = bench::load_baseline(&path, 2021);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        vec![1],
+        loaded.unwrap().days.into_keys().collect::<Vec<_>>()
+    );
+    assert!(other.is_err());
+}
+
+#[test]
+fn day05_reads_any_drawing() {
+    let input = concat!(
+        "                                    [J]\n",
+        "[A]         [D]                     [K]\n",
+        "[B] [C]     [E] [F] [G] [H]     [I] [L]\n",
+        " 1   2   3   4   5   6   7   8   9  10\n",
+        "\n",
+        "move 1 from 1 to 3\n",
+        "move 2 from 10 to 8\n",
+    );
+    let puzzle = dispatch(2022, 5).unwrap();
+
+    assert_eq!(Answer::from("BCADFGHKIL"), puzzle.part_one(input).unwrap());
+    assert_eq!(Answer::from("BCADFGHJIL"), puzzle.part_two(input).unwrap());
+    assert!(puzzle.validate(input).is_empty());
+
+    let typos = format!("{}mvoe 1 from 2 to 1\nmove 1 from 2 to 11\n\n", input);
+    assert!(puzzle.part_one(&typos).is_err());
+    let lines = puzzle
+        .validate(&typos)
+        .iter()
+        .map(|d| d.line)
+        .collect::<Vec<_>>();
+    assert_eq!(vec![Some(8), Some(9)], lines);
+}
+
+#[test]
+fn answers_serialize_by_kind() {
+    let answers = vec![
+        Answer::Integer(1514285714288),
+        Answer::Text("2=-1=0".into()),
+        Answer::Grid(vec!["##..".into(), "..##".into()]),
+    ];
+    let json = serde_json::to_string(&answers).unwrap();
+    assert_eq!(r###"[1514285714288,"2=-1=0",["##..","..##"]]"###, json);
+    assert_eq!(answers, serde_json::from_str::<Vec<Answer>>(&json).unwrap());
+}
+
+#[test]
+fn ocr_reads_letters() {
+    let image = [
+        "###..#..#.####.",
+        "#..#.#..#....#.",
+        "#..#.####...#..",
+        "###..#..#..#...",
+        "#.#..#..#.#....",
+        "#..#.#..#.####.",
+    ];
+    assert_eq!("RHZ", aoc::ocr::recognize(&image).unwrap());
+    assert!(aoc::ocr::recognize(&image[..5]).is_err());
+
+    let blank = ["....."; 6];
+    assert!(aoc::ocr::recognize(&blank).is_err());
+}
+
+#[test]
+fn solvers_are_callable_as_a_library() {
+    let input = load_example(2022, 25).unwrap();
+    assert_eq!(
+        Answer::from("2=-1=0"),
+        solve(2022, 25, Part::One, &input).unwrap()
+    );
+    assert_eq!(
+        Answer::from("2=-1=0"),
+        y2022::solve(25, Part::One, &input).unwrap()
+    );
+    assert!(solve(2022, 26, Part::One, &input).is_err());
+
+    let numbers = y2022::day25::parse(&input).unwrap();
+    assert_eq!(13, numbers.len());
+    assert_eq!(Ok(1747), numbers[0].to_decimal().map_err(|e| e.to_string()));
+    assert!(y2022::day25::parse("12\n3").is_err());
+}
+
+#[test]
+fn validation_points_at_every_problem() {
+    let puzzle = dispatch(2022, 9).unwrap();
+    let diagnostics = puzzle.validate("R 4\nX 3\nL 2\nU x\nD -3\n");
+    let positions = diagnostics.iter().map(|d| (d.line, d.column));
+    assert_eq!(
+        vec![(Some(2), Some(1)), (Some(4), Some(3)), (Some(5), Some(3))],
+        positions.collect::<Vec<_>>()
+    );
+    assert_eq!("U x", diagnostics[1].snippet);
+
+    let puzzle = dispatch(2022, 12).unwrap();
+    let diagnostics = puzzle.validate("Sabc\nabcd\n");
+    assert_eq!(1, diagnostics.len());
+    assert_eq!(None, diagnostics[0].line);
+
+    let input = load_example(2022, 12).unwrap();
+    assert!(puzzle.validate(&input).is_empty());
+}
+
+#[test]
+fn grids_parse_and_render() {
+    let grid = Grid::parse("123\n456\n", "a digit", |c| c.to_digit(10)).unwrap();
+    assert_eq!((3, 2), (grid.width(), grid.height()));
+    assert_eq!(Some(&6), grid.get(pt!(2, 1)));
+    assert_eq!(None, grid.get(pt!(3, 1)));
+    let neighbours = grid.neighbours4(pt!(1, 1)).map(|p| grid[p]);
+    assert_eq!(vec![2, 6, 4], neighbours.collect::<Vec<_>>());
+    assert_eq!(
+        vec![3, 6],
+        grid.column(2).unwrap().copied().collect::<Vec<_>>()
+    );
+    assert!(grid.column(3).is_none());
+    assert_eq!(Some(&[4, 5, 6][..]), grid.row(1));
+    assert_eq!(None, grid.row(2));
+    assert_eq!(3, grid.columns().count());
+    assert_eq!("123\n456\n", grid.to_string());
+    assert!(Grid::parse("12\n3\n", "a digit", |c| c.to_digit(10)).is_err());
+    assert!(Grid::parse("", "a digit", |c| c.to_digit(10)).is_err());
+
+    for empty in [
+        Grid::new(0, 0, '.'),
+        Grid::new(0, 3, '.'),
+        Grid::new(3, 0, '.'),
+    ] {
+        assert_eq!((0, 0), (empty.width(), empty.height()));
+        assert_eq!(0, empty.rows().count());
+        assert!(empty.column(0).is_none());
+        assert_eq!(None, empty.row(0));
+        assert_eq!("", empty.to_string());
+    }
+
+    let blank = [' ', '.'];
+    let sparse = SparseGrid::parse(" #\n#.\n", &blank, "'#'", |c| (c == '#').then_some(c));
+    let sparse = sparse.unwrap();
+    assert_eq!(2, sparse.len());
+    assert_eq!(Some((pt!(0, 0), pt!(1, 1))), sparse.bounds());
+    assert_eq!(" #\n# \n", sparse.to_string());
+}
+
+#[test]
+fn searches_find_shortest_paths() {
+    let grid = Grid::parse("..#\n.##\n...\n", "'.' or '#'", |c| Some(c == '#')).unwrap();
+    let open = |&p: &P2<i32>| {
+        grid.neighbours4(p)
+            .filter(|&n| !grid[n])
+            .collect::<Vec<_>>()
+    };
+    let (src, dst) = (pt!(0, 0), pt!(2, 2));
+
+    let search = bfs([src], open, |&p| p == dst);
+    assert_eq!(Some(4), search.goal_cost());
+    let path = vec![pt!(0, 0), pt!(0, 1), pt!(0, 2), pt!(1, 2), pt!(2, 2)];
+    assert_eq!(Some(path), search.goal_path());
+
+    let weighted = |p: &P2<i32>| open(p).into_iter().map(|n| (n, 2));
+    let search = dijkstra([src], weighted, |&p| p == dst);
+    assert_eq!(Some(8), search.goal_cost());
+    let manhattan = |p: &P2<i32>| 2 * ((dst.x - p.x).abs() + (dst.y - p.y).abs());
+    let search = astar([src], weighted, manhattan, |&p| p == dst);
+    assert_eq!(Some(8), search.goal_cost());
+
+    let search = bfs([src, pt!(1, 2)], open, |&p| p == dst);
+    assert_eq!(Some(1), search.goal_cost());
+    let search = bfs([src], open, |_| false);
+    assert_eq!(None, search.goal);
+    assert_eq!(6, search.costs().len());
+}
+
+#[test]
+fn parse_errors_carry_the_offending_text() {
+    let puzzle = dispatch(2022, 14).unwrap();
+    let diagnostics = puzzle.validate("498,4 -> 498,6\n498,4 -> 498,x\n");
+    let positions = diagnostics.iter().map(|d| (d.line, d.column));
+    assert_eq!(vec![(Some(2), Some(14))], positions.collect::<Vec<_>>());
+
+    let err = solve(2022, 18, Part::One, "1,2,3\n1,x,3\n").unwrap_err();
+    let err = err.downcast_ref::<ParseError>().unwrap();
+    assert_eq!(ParseErrorKind::NoMatch, err.kind);
+    assert_eq!("1,x,3", err.text);
+    assert_eq!(r"(-?\d+).(-?\d+).(-?\d+)", err.pattern);
 }